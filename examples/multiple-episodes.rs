@@ -1,3 +1,5 @@
+#![allow(clippy::println_empty_string)]
+
 use torrent_name_parser::Metadata;
 
 pub fn main() {
@@ -1,3 +1,5 @@
+#![allow(clippy::println_empty_string)]
+
 extern crate torrent_name_parser;
 
 use torrent_name_parser::Metadata;
@@ -0,0 +1,156 @@
+use std::fmt;
+
+/// Audio codec, ordered roughly from lowest to highest fidelity.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AudioCodec {
+    Mp3,
+    Aac,
+    Opus,
+    DolbyDigital,
+    DolbyDigitalPlus,
+    Dts,
+    Flac,
+    DtsHd,
+    TrueHd,
+    DtsX,
+}
+
+impl AudioCodec {
+    pub(crate) fn from_tag(tag: &str) -> Option<Self> {
+        let plus = tag.contains('+');
+        let tag: String = tag
+            .chars()
+            .filter(|c| c.is_ascii_alphanumeric())
+            .collect::<String>()
+            .to_lowercase();
+        if tag.starts_with("mp3") {
+            Some(AudioCodec::Mp3)
+        } else if tag.starts_with("aac") {
+            Some(AudioCodec::Aac)
+        } else if tag.starts_with("opus") {
+            Some(AudioCodec::Opus)
+        } else if tag.starts_with("flac") {
+            Some(AudioCodec::Flac)
+        } else if tag.starts_with("truehd") {
+            Some(AudioCodec::TrueHd)
+        } else if tag.starts_with("ddp") || tag.starts_with("eac3") || (plus && tag == "dd") {
+            Some(AudioCodec::DolbyDigitalPlus)
+        } else if tag.starts_with("dd") || tag.starts_with("ac3") {
+            Some(AudioCodec::DolbyDigital)
+        } else if tag.starts_with("dtshd") {
+            Some(AudioCodec::DtsHd)
+        } else if tag.starts_with("dtsx") {
+            Some(AudioCodec::DtsX)
+        } else if tag.starts_with("dts") {
+            Some(AudioCodec::Dts)
+        } else {
+            None
+        }
+    }
+}
+
+impl fmt::Display for AudioCodec {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            AudioCodec::Mp3 => "MP3",
+            AudioCodec::Aac => "AAC",
+            AudioCodec::Opus => "Opus",
+            AudioCodec::DolbyDigital => "DD",
+            AudioCodec::DolbyDigitalPlus => "DD+",
+            AudioCodec::Dts => "DTS",
+            AudioCodec::Flac => "FLAC",
+            AudioCodec::DtsHd => "DTS-HD",
+            AudioCodec::TrueHd => "TrueHD",
+            AudioCodec::DtsX => "DTS:X",
+        };
+        f.write_str(name)
+    }
+}
+
+/// A channel layout such as `5.1`: full range channels and LFE channels.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Channels {
+    main: u8,
+    lfe: u8,
+}
+
+impl Channels {
+    pub fn new(main: u8, lfe: u8) -> Self {
+        Self { main, lfe }
+    }
+
+    /// Reads `5.1` or `51`.
+    pub(crate) fn from_tag(tag: &str) -> Option<Self> {
+        let mut digits = tag.chars().filter_map(|c| c.to_digit(10));
+        let main = digits.next()? as u8;
+        let lfe = digits.next()? as u8;
+        Some(Self::new(main, lfe))
+    }
+
+    pub fn main(&self) -> u8 {
+        self.main
+    }
+    pub fn lfe(&self) -> u8 {
+        self.lfe
+    }
+    pub fn total(&self) -> u8 {
+        self.main + self.lfe
+    }
+}
+
+impl fmt::Display for Channels {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}.{}", self.main, self.lfe)
+    }
+}
+
+/// What is known about the audio track: codec, channel layout and object audio.
+///
+///```
+/// use torrent_name_parser::{AudioCodec, Channels, Metadata};
+///
+/// let m = Metadata::from("Dune.2021.2160p.UHD.BluRay.TrueHD.7.1.Atmos.x265-GRP").unwrap();
+/// let audio = m.audio_format().unwrap();
+/// assert_eq!(audio.codec(), Some(AudioCodec::TrueHd));
+/// assert_eq!(audio.channels(), Some(Channels::new(7, 1)));
+/// assert!(audio.atmos());
+///```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AudioFormat {
+    codec: Option<AudioCodec>,
+    channels: Option<Channels>,
+    atmos: bool,
+}
+
+impl AudioFormat {
+    /// `None` unless there is a codec or Atmos, so `Dual Audio` has no format.
+    pub(crate) fn from_parts(
+        codec: Option<&str>,
+        channels: Option<&str>,
+        atmos: bool,
+    ) -> Option<Self> {
+        let codec = codec.and_then(AudioCodec::from_tag);
+        if codec.is_none() && !atmos {
+            return None;
+        }
+        Some(Self {
+            codec,
+            channels: channels.and_then(Channels::from_tag),
+            atmos,
+        })
+    }
+
+    pub fn codec(&self) -> Option<AudioCodec> {
+        self.codec
+    }
+    pub fn channels(&self) -> Option<Channels> {
+        self.channels
+    }
+    /// Whether the track carries Dolby Atmos objects.
+    pub fn atmos(&self) -> bool {
+        self.atmos
+    }
+}
@@ -0,0 +1,123 @@
+//! Just enough of bencode to read the `info` dictionary of a `.torrent` file.
+
+use std::convert::TryFrom;
+
+/// Deeper nesting than any real torrent, to stop crafted input exhausting the stack.
+const MAX_DEPTH: usize = 64;
+
+#[derive(Debug, PartialEq)]
+pub(crate) enum Value<'a> {
+    Int(i64),
+    Bytes(&'a [u8]),
+    List(Vec<Value<'a>>),
+    Dict(Vec<(&'a [u8], Value<'a>)>),
+}
+
+impl<'a> Value<'a> {
+    pub(crate) fn get(&self, key: &str) -> Option<&Value<'a>> {
+        match self {
+            Value::Dict(entries) => entries
+                .iter()
+                .find(|(k, _)| *k == key.as_bytes())
+                .map(|(_, v)| v),
+            _ => None,
+        }
+    }
+    pub(crate) fn as_int(&self) -> Option<i64> {
+        match self {
+            Value::Int(n) => Some(*n),
+            _ => None,
+        }
+    }
+    pub(crate) fn as_str(&self) -> Option<&'a str> {
+        match self {
+            Value::Bytes(bytes) => std::str::from_utf8(bytes).ok(),
+            _ => None,
+        }
+    }
+    pub(crate) fn as_list(&self) -> Option<&[Value<'a>]> {
+        match self {
+            Value::List(items) => Some(items),
+            _ => None,
+        }
+    }
+}
+
+/// Decodes a single value that must take up all of `input`. The error is the byte
+/// offset where decoding failed.
+pub(crate) fn decode(input: &[u8]) -> Result<Value<'_>, usize> {
+    let mut decoder = Decoder { input, pos: 0 };
+    let value = decoder.value(0)?;
+    if decoder.pos != input.len() {
+        return Err(decoder.pos);
+    }
+    Ok(value)
+}
+
+struct Decoder<'a> {
+    input: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Decoder<'a> {
+    fn peek(&self) -> Result<u8, usize> {
+        self.input.get(self.pos).copied().ok_or(self.pos)
+    }
+
+    fn value(&mut self, depth: usize) -> Result<Value<'a>, usize> {
+        if depth > MAX_DEPTH {
+            return Err(self.pos);
+        }
+        match self.peek()? {
+            b'i' => {
+                self.pos += 1;
+                let n = self.number(b'e')?;
+                Ok(Value::Int(n))
+            }
+            b'l' => {
+                self.pos += 1;
+                let mut items = Vec::new();
+                while self.peek()? != b'e' {
+                    items.push(self.value(depth + 1)?);
+                }
+                self.pos += 1;
+                Ok(Value::List(items))
+            }
+            b'd' => {
+                self.pos += 1;
+                let mut entries = Vec::new();
+                while self.peek()? != b'e' {
+                    let key = self.bytes()?;
+                    entries.push((key, self.value(depth + 1)?));
+                }
+                self.pos += 1;
+                Ok(Value::Dict(entries))
+            }
+            b'0'..=b'9' => self.bytes().map(Value::Bytes),
+            _ => Err(self.pos),
+        }
+    }
+
+    /// A length prefixed byte string, eg: `4:spam`.
+    fn bytes(&mut self) -> Result<&'a [u8], usize> {
+        let start = self.pos;
+        let len = usize::try_from(self.number(b':')?).map_err(|_| start)?;
+        let end = self.pos.checked_add(len).ok_or(start)?;
+        let bytes = self.input.get(self.pos..end).ok_or(self.pos)?;
+        self.pos = end;
+        Ok(bytes)
+    }
+
+    /// Decimal digits up to `end`, which is consumed.
+    fn number(&mut self, end: u8) -> Result<i64, usize> {
+        let start = self.pos;
+        let len = self.input[start..]
+            .iter()
+            .position(|b| *b == end)
+            .ok_or(start)?;
+        let text = std::str::from_utf8(&self.input[start..start + len]).map_err(|_| start)?;
+        let n = text.parse().map_err(|_| start)?;
+        self.pos = start + len + 1;
+        Ok(n)
+    }
+}
@@ -0,0 +1,182 @@
+use std::io::{self, BufRead, Write};
+use std::process;
+use torrent_name_parser::error::ErrorMatch;
+use torrent_name_parser::Metadata;
+
+const USAGE: &str = "Usage: tnp [-f table|json|tsv] [NAME]...
+
+Parse torrent names given as arguments, or one per line on stdin.
+
+Options:
+  -f, --format <FORMAT>  Output format: table (default), json or tsv
+  -h, --help             Print this help
+
+A name that could not be parsed gets a row with only the name and the reason in
+the error column, and makes tnp exit with status 1.";
+
+const COLUMNS: &[&str] = &[
+    "title",
+    "year",
+    "season",
+    "episode",
+    "resolution",
+    "quality",
+    "codec",
+    "audio",
+    "group",
+    "extension",
+    "error",
+];
+
+#[derive(Clone, Copy)]
+enum Format {
+    Table,
+    Json,
+    Tsv,
+}
+
+fn usage_error(message: &str) -> ! {
+    eprintln!("tnp: {}\n\n{}", message, USAGE);
+    process::exit(2);
+}
+
+fn row(m: &Metadata) -> Vec<String> {
+    let number = |n: Option<i32>| n.map(|n| n.to_string()).unwrap_or_default();
+    let text = |s: Option<&str>| s.unwrap_or_default().to_string();
+    vec![
+        m.title().to_string(),
+        number(m.year()),
+        number(m.season()),
+        number(m.episode()),
+        text(m.resolution()),
+        text(m.quality()),
+        text(m.codec()),
+        text(m.audio()),
+        text(m.group()),
+        text(m.extension()),
+        String::new(),
+    ]
+}
+
+fn error_row(e: &ErrorMatch) -> Vec<String> {
+    let mut row = vec![String::new(); COLUMNS.len() - 1];
+    row.push(e.to_string());
+    row
+}
+
+fn print_table(out: &mut impl Write, rows: &[Vec<String>]) -> io::Result<()> {
+    let header: Vec<String> = std::iter::once(&"name")
+        .chain(COLUMNS)
+        .map(|c| c.to_uppercase())
+        .collect();
+    let mut widths: Vec<usize> = header.iter().map(|h| h.chars().count()).collect();
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    for row in std::iter::once(&header).chain(rows) {
+        let cells: Vec<String> = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+            .collect();
+        writeln!(out, "{}", cells.join("  ").trim_end())?;
+    }
+    Ok(())
+}
+
+fn print_json(
+    out: &mut impl Write,
+    name: &str,
+    result: &Result<Metadata, ErrorMatch>,
+) -> io::Result<()> {
+    let line = match result {
+        Ok(m) => serde_json::json!({ "name": name, "metadata": m }),
+        Err(e) => serde_json::json!({ "name": name, "error": e.to_string() }),
+    };
+    writeln!(out, "{}", line)
+}
+
+fn main() {
+    let mut format = Format::Table;
+    let mut names = Vec::new();
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                return;
+            }
+            "-f" | "--format" => {
+                format = match args.next().as_deref() {
+                    Some("table") => Format::Table,
+                    Some("json") => Format::Json,
+                    Some("tsv") => Format::Tsv,
+                    Some(other) => usage_error(&format!("unknown format '{}'", other)),
+                    None => usage_error("missing value for --format"),
+                }
+            }
+            "--" => names.extend(args.by_ref()),
+            _ if arg.starts_with('-') && arg.len() > 1 => {
+                usage_error(&format!("unknown option '{}'", arg))
+            }
+            _ => names.push(arg),
+        }
+    }
+
+    if names.is_empty() {
+        for line in io::stdin().lock().lines() {
+            match line {
+                Ok(line) if !line.trim().is_empty() => names.push(line),
+                Ok(_) => {}
+                Err(e) => {
+                    eprintln!("tnp: failed to read stdin: {}", e);
+                    process::exit(2);
+                }
+            }
+        }
+    }
+
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+    let mut rows = Vec::new();
+    let mut failed = false;
+
+    if let Format::Tsv = format {
+        let _ = writeln!(out, "name\t{}", COLUMNS.join("\t"));
+    }
+    for name in &names {
+        let result = Metadata::from(name);
+        if let Err(e) = &result {
+            failed = true;
+            if !matches!(format, Format::Json) {
+                eprintln!("tnp: {}: {}", name, e);
+            }
+        }
+        let cells = match &result {
+            Ok(m) => row(m),
+            Err(e) => error_row(e),
+        };
+        let written = match format {
+            Format::Json => print_json(&mut out, name, &result),
+            Format::Tsv => writeln!(out, "{}\t{}", name, cells.join("\t")),
+            Format::Table => {
+                rows.push(std::iter::once(name.clone()).chain(cells).collect());
+                Ok(())
+            }
+        };
+        if written.is_err() {
+            // stdout was closed, eg: piped into `head`
+            process::exit(if failed { 1 } else { 0 });
+        }
+    }
+    if let Format::Table = format {
+        let _ = print_table(&mut out, &rows);
+    }
+
+    if failed {
+        process::exit(1);
+    }
+}
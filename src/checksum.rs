@@ -0,0 +1,40 @@
+use std::io::{self, Read};
+
+/// Lookup table for the reflected CRC-32 polynomial used by zip and fansub releases.
+const TABLE: [u32; 256] = {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xEDB8_8320
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+};
+
+/// The CRC-32 of everything read from `reader`.
+pub(crate) fn crc32(mut reader: impl Read) -> io::Result<u32> {
+    let mut crc = !0u32;
+    let mut buf = [0u8; 64 * 1024];
+    loop {
+        let n = match reader.read(&mut buf) {
+            Ok(0) => break,
+            Ok(n) => n,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        };
+        for byte in &buf[..n] {
+            crc = TABLE[((crc ^ *byte as u32) & 0xFF) as usize] ^ (crc >> 8);
+        }
+    }
+    Ok(!crc)
+}
@@ -0,0 +1,66 @@
+use std::fmt;
+
+/// The broadcast date of a daily show episode, eg: `2023.10.05`.
+///
+///```
+/// use torrent_name_parser::Metadata;
+///
+/// let m = Metadata::from("The.Daily.Show.2023.10.05.Guest.Name.720p.WEB.h264-GROUP").unwrap();
+/// let date = m.air_date().unwrap();
+/// assert_eq!((date.year(), date.month(), date.day()), (2023, 10, 5));
+/// assert_eq!(date.to_string(), "2023-10-05");
+/// assert_eq!(m.title(), "The Daily Show");
+/// assert!(m.is_show());
+///```
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AirDate {
+    year: i32,
+    month: u8,
+    day: u8,
+}
+
+impl AirDate {
+    /// `None` if the day does not exist in that month.
+    pub fn new(year: i32, month: u8, day: u8) -> Option<Self> {
+        let leap = (year % 4 == 0 && year % 100 != 0) || year % 400 == 0;
+        let days = match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if leap => 29,
+            2 => 28,
+            _ => return None,
+        };
+        if day == 0 || day > days {
+            return None;
+        }
+        Some(Self { year, month, day })
+    }
+
+    /// Reads the parts as written, where a two digit year below 70 is in the 2000s.
+    pub(crate) fn from_parts(year: &str, month: &str, day: &str) -> Option<Self> {
+        let mut year: i32 = year.parse().ok()?;
+        if year < 70 {
+            year += 2000;
+        } else if year < 100 {
+            year += 1900;
+        }
+        Self::new(year, month.parse().ok()?, day.parse().ok()?)
+    }
+
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+}
+
+impl fmt::Display for AirDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
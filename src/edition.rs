@@ -0,0 +1,71 @@
+use std::fmt;
+
+/// A cut or release of a film, for telling apart copies of the same title.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Edition {
+    DirectorsCut,
+    Theatrical,
+    Remastered,
+    Criterion,
+    Imax,
+    SpecialEdition,
+    FinalCut,
+    UltimateEdition,
+    AnniversaryEdition,
+    OpenMatte,
+}
+
+impl Edition {
+    /// Every edition in a tag such as `Directors.Cut.Remastered`, in the order they appear.
+    pub(crate) fn from_tag(tag: &str) -> Vec<Self> {
+        let tag: String = tag
+            .chars()
+            .filter(|c| c.is_ascii_alphanumeric())
+            .collect::<String>()
+            .to_lowercase();
+        let keywords = [
+            ("directorscut", Edition::DirectorsCut),
+            ("directorcut", Edition::DirectorsCut),
+            ("theatrical", Edition::Theatrical),
+            ("remaster", Edition::Remastered),
+            ("criterion", Edition::Criterion),
+            ("imax", Edition::Imax),
+            ("specialedition", Edition::SpecialEdition),
+            ("finalcut", Edition::FinalCut),
+            ("ultimate", Edition::UltimateEdition),
+            ("anniversary", Edition::AnniversaryEdition),
+            ("openmatte", Edition::OpenMatte),
+        ];
+        let mut found: Vec<(usize, Self)> = keywords
+            .iter()
+            .filter_map(|(keyword, edition)| tag.find(keyword).map(|pos| (pos, *edition)))
+            .collect();
+        found.sort_by_key(|(pos, _)| *pos);
+        let mut editions = Vec::new();
+        for (_, edition) in found {
+            if !editions.contains(&edition) {
+                editions.push(edition);
+            }
+        }
+        editions
+    }
+}
+
+impl fmt::Display for Edition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Edition::DirectorsCut => "Director's Cut",
+            Edition::Theatrical => "Theatrical",
+            Edition::Remastered => "Remastered",
+            Edition::Criterion => "Criterion",
+            Edition::Imax => "IMAX",
+            Edition::SpecialEdition => "Special Edition",
+            Edition::FinalCut => "Final Cut",
+            Edition::UltimateEdition => "Ultimate Edition",
+            Edition::AnniversaryEdition => "Anniversary Edition",
+            Edition::OpenMatte => "Open Matte",
+        };
+        f.write_str(name)
+    }
+}
@@ -1,25 +1,150 @@
+use crate::metadata::Metadata;
+use crate::span::{Field, Span};
 use std::error::Error;
 use std::fmt;
 
-#[derive(Debug)]
-pub struct ErrorMatch {
-    matches: Vec<(&'static str, Option<String>)>,
+/// Why a torrent name could not be parsed.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ErrorMatch {
+    /// The name was empty or only whitespace.
+    EmptyInput,
+    /// Every part of the name was taken by other fields, so there was nothing left for the title.
+    NoTitle {
+        name: String,
+        /// Everything that was found, without a title.
+        partial: Box<Metadata>,
+        /// The full matches that pushed the start and end of the title together.
+        consumed_by: Vec<Span>,
+    },
+    /// A season, episode or year did not fit in an `i32`.
+    NumberOutOfRange { field: Field, value: String },
 }
 
 impl ErrorMatch {
-    pub fn new(matches: Vec<(&'static str, Option<String>)>) -> ErrorMatch {
-        ErrorMatch { matches }
+    /// The fields that were extracted before the title went missing.
+    ///
+    ///```
+    /// use torrent_name_parser::Metadata;
+    ///
+    /// let err = Metadata::from("[Rip Time] S01E12 [1080p]").unwrap_err();
+    /// assert_eq!(err.partial().unwrap().episode(), Some(12));
+    /// assert_eq!(err.partial().unwrap().resolution(), Some("1080p"));
+    ///```
+    pub fn partial(&self) -> Option<&Metadata> {
+        match self {
+            ErrorMatch::NoTitle { partial, .. } => Some(partial),
+            _ => None,
+        }
+    }
+
+    /// The matches that left no room for the title, empty unless this is `NoTitle`.
+    pub fn consumed_by(&self) -> &[Span] {
+        match self {
+            ErrorMatch::NoTitle { consumed_by, .. } => consumed_by,
+            _ => &[],
+        }
     }
 }
 
 impl fmt::Display for ErrorMatch {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{:?}", self.matches)
+        match self {
+            ErrorMatch::EmptyInput => write!(f, "torrent name is empty"),
+            ErrorMatch::NoTitle {
+                name, consumed_by, ..
+            } => {
+                write!(f, "couldn't find a title in {:?}", name)?;
+                for (i, span) in consumed_by.iter().enumerate() {
+                    let sep = if i == 0 { ", consumed by" } else { " and" };
+                    // A deserialized or hand-built error may not point into `name`.
+                    match name.get(span.range()) {
+                        Some(text) => write!(f, "{} {:?} {:?}", sep, span.field(), text)?,
+                        None => write!(f, "{} {:?} at {:?}", sep, span.field(), span.range())?,
+                    }
+                }
+                Ok(())
+            }
+            ErrorMatch::NumberOutOfRange { field, value } => {
+                write!(f, "{:?} {:?} is out of range", field, value)
+            }
+        }
+    }
+}
+
+impl Error for ErrorMatch {}
+
+/// Why a magnet URI could not be read.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MagnetError {
+    /// The URI does not start with `magnet:?`.
+    NotMagnet,
+    /// There is no `xt=urn:btih:` info-hash.
+    MissingInfoHash,
+    /// The info-hash is neither 40 hex digits nor 32 base32 characters.
+    InvalidInfoHash(String),
+}
+
+impl fmt::Display for MagnetError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MagnetError::NotMagnet => write!(f, "not a magnet URI"),
+            MagnetError::MissingInfoHash => write!(f, "magnet URI has no BitTorrent info-hash"),
+            MagnetError::InvalidInfoHash(hash) => write!(f, "invalid info-hash {:?}", hash),
+        }
+    }
+}
+
+impl Error for MagnetError {}
+
+/// A problem found by `Metadata::parse_lenient`, which still returns what it could read.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Warning {
+    /// The name was empty or only whitespace.
+    EmptyInput,
+    /// There was nothing left for the title, see `ErrorMatch::NoTitle`.
+    NoTitle { consumed_by: Vec<Span> },
+    /// A season, episode or year did not fit in an `i32` and was skipped.
+    NumberOutOfRange { field: Field, value: String },
+}
+
+/// Why a `.torrent` file could not be read.
+#[cfg(feature = "torrent")]
+#[derive(Debug)]
+pub enum TorrentError {
+    Io(std::io::Error),
+    /// The file is not valid bencode, failing at this byte offset.
+    Bencode(usize),
+    /// A key every torrent needs, eg: `info.name`, was missing or not text.
+    Missing(&'static str),
+}
+
+#[cfg(feature = "torrent")]
+impl fmt::Display for TorrentError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TorrentError::Io(e) => write!(f, "couldn't read torrent: {}", e),
+            TorrentError::Bencode(offset) => write!(f, "invalid bencode at byte {}", offset),
+            TorrentError::Missing(key) => write!(f, "torrent has no {}", key),
+        }
+    }
+}
+
+#[cfg(feature = "torrent")]
+impl Error for TorrentError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            TorrentError::Io(e) => Some(e),
+            _ => None,
+        }
     }
 }
 
-impl Error for ErrorMatch {
-    fn description(&self) -> &str {
-        "Couldn't find a title."
+#[cfg(feature = "torrent")]
+impl From<std::io::Error> for TorrentError {
+    fn from(e: std::io::Error) -> Self {
+        TorrentError::Io(e)
     }
 }
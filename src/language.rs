@@ -0,0 +1,96 @@
+/// ISO 639-2 codes for the language names and abbreviations used in release names.
+fn code(token: &str) -> Option<&'static str> {
+    let code = match token {
+        "eng" | "english" => "eng",
+        "fre" | "fra" | "french" | "truefrench" | "vf" | "vff" | "vfq" => "fra",
+        "ger" | "deu" | "german" => "deu",
+        "ita" | "italian" => "ita",
+        "spa" | "esp" | "spanish" => "spa",
+        "rus" | "russian" => "rus",
+        "jap" | "jpn" | "japanese" => "jpn",
+        "por" | "portuguese" => "por",
+        "dut" | "nld" | "dutch" => "nld",
+        "kor" | "korean" => "kor",
+        "chi" | "chinese" => "zho",
+        "hin" | "hindi" => "hin",
+        "pol" | "polish" => "pol",
+        "swe" => "swe",
+        "nor" => "nor",
+        "dan" => "dan",
+        "fin" => "fin",
+        "tur" => "tur",
+        "ara" => "ara",
+        "hun" => "hun",
+        "cze" => "ces",
+        _ => return None,
+    };
+    Some(code)
+}
+
+fn is_subs(token: &str) -> bool {
+    matches!(token, "sub" | "subs" | "subbed")
+}
+
+fn push(codes: &mut Vec<String>, code: &str) {
+    if !codes.iter().any(|c| c == code) {
+        codes.push(code.to_string());
+    }
+}
+
+/// Drops `und` when a real language was also found.
+fn tidy(mut codes: Vec<String>) -> Vec<String> {
+    if codes.len() > 1 {
+        codes.retain(|c| c != "und");
+    }
+    codes
+}
+
+/// Splits a run of language tags, eg: `ITA.ENG` or `German.DL.Multi-Subs`, into
+/// audio and subtitle languages.
+///
+/// `MULTi` gives `mul`, while `Subbed` and `Dubbed` name no language and give `und`.
+/// `DL` (dual language) marks the original audio as also present, which is not
+/// named and so is not reported.
+pub(crate) fn audio_and_subtitles(tag: &str) -> (Vec<String>, Vec<String>) {
+    let tag = tag.to_lowercase();
+    let tokens: Vec<&str> = tag
+        .split([' ', '.', '-', '_'])
+        .filter(|t| !t.is_empty())
+        .collect();
+    let mut audio = Vec::new();
+    let mut subtitles = Vec::new();
+
+    let mut i = 0;
+    while i < tokens.len() {
+        let token = tokens[i];
+        let next_is_subs = tokens.get(i + 1).is_some_and(|t| is_subs(t));
+        match token {
+            "multi" if next_is_subs => {
+                push(&mut subtitles, "mul");
+                i += 1;
+            }
+            "multisub" | "multisubs" => push(&mut subtitles, "mul"),
+            "multi" => push(&mut audio, "mul"),
+            "dual" if tokens.get(i + 1) == Some(&"audio") => {
+                push(&mut audio, "mul");
+                i += 1;
+            }
+            "vostfr" | "subfrench" => push(&mut subtitles, "fra"),
+            "vost" | "sub" | "subs" | "subbed" => push(&mut subtitles, "und"),
+            "esub" | "esubs" => push(&mut subtitles, "eng"),
+            "dub" | "dubbed" => push(&mut audio, "und"),
+            _ => {
+                if let Some(code) = code(token) {
+                    if next_is_subs {
+                        push(&mut subtitles, code);
+                        i += 1;
+                    } else {
+                        push(&mut audio, code);
+                    }
+                }
+            }
+        }
+        i += 1;
+    }
+    (tidy(audio), tidy(subtitles))
+}
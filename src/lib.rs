@@ -1,11 +1,37 @@
 #[macro_use]
 extern crate lazy_static;
-extern crate regex;
+/// The regex crate `Pattern::new` takes a `Regex` from.
+pub extern crate regex;
 
+mod audio;
+#[cfg(feature = "torrent")]
+mod bencode;
+mod checksum;
+mod date;
+mod edition;
 pub mod error;
+mod language;
+mod magnet;
 mod metadata;
+mod parser;
 mod pattern;
+mod service;
+mod span;
 #[cfg(test)]
 mod test;
+#[cfg(feature = "torrent")]
+mod torrent;
+mod video;
 
+pub use audio::{AudioCodec, AudioFormat, Channels};
+pub use date::AirDate;
+pub use edition::Edition;
+pub use magnet::Magnet;
 pub use metadata::Metadata;
+pub use parser::{Parser, ParserBuilder};
+pub use pattern::Pattern;
+pub use service::StreamingService;
+pub use span::{Field, Span};
+#[cfg(feature = "torrent")]
+pub use torrent::{TorrentFile, TorrentMetadata};
+pub use video::{HdrFormat, Resolution, Source, VideoCodec};
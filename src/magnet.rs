@@ -0,0 +1,157 @@
+use crate::error::{ErrorMatch, MagnetError};
+use crate::metadata::Metadata;
+use crate::parser::Parser;
+use std::borrow::Cow;
+
+/// What a magnet URI says about a torrent: its info-hash, trackers and parsed name.
+///
+///```
+/// use torrent_name_parser::Metadata;
+///
+/// let magnet = Metadata::from_magnet(
+///     "magnet:?xt=urn:btih:C12FE1C06BBA254A9DC9F519B335AA7C1367A88A\
+///      &dn=The+Walking+Dead+S05E03+720p+HDTV+x264-ASAP%5Bettv%5D\
+///      &tr=udp%3A%2F%2Ftracker.example.org%3A1337",
+/// )
+/// .unwrap();
+/// assert_eq!(magnet.info_hash(), "c12fe1c06bba254a9dc9f519b335aa7c1367a88a");
+/// assert_eq!(magnet.trackers(), &["udp://tracker.example.org:1337"]);
+/// let m = magnet.metadata().unwrap().unwrap();
+/// assert_eq!(m.title(), "The Walking Dead");
+/// assert_eq!(m.episode(), Some(3));
+///```
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Magnet {
+    info_hash: String,
+    name: Option<String>,
+    metadata: Option<Result<Metadata, ErrorMatch>>,
+    trackers: Vec<String>,
+}
+
+impl Magnet {
+    /// The BitTorrent info-hash as 40 lowercase hex digits, even when the URI used base32.
+    pub fn info_hash(&self) -> &str {
+        &self.info_hash
+    }
+    /// The decoded display name, `dn`, which magnets may leave out.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+    /// The parsed display name, or `None` without one.
+    pub fn metadata(&self) -> Option<Result<&Metadata, &ErrorMatch>> {
+        self.metadata.as_ref().map(Result::as_ref)
+    }
+    /// Every `tr` tracker, in the order given.
+    pub fn trackers(&self) -> &[String] {
+        &self.trackers
+    }
+}
+
+impl Metadata {
+    /// Reads a `magnet:?xt=urn:btih:...&dn=...` link and parses its display name.
+    pub fn from_magnet(uri: &str) -> Result<Magnet, MagnetError> {
+        Parser::default().parse_magnet(uri)
+    }
+}
+
+impl Parser {
+    /// Reads a magnet link like `Metadata::from_magnet`, parsing its display name with
+    /// this parser.
+    pub fn parse_magnet(&self, uri: &str) -> Result<Magnet, MagnetError> {
+        let query = uri
+            .trim()
+            .strip_prefix("magnet:?")
+            .ok_or(MagnetError::NotMagnet)?;
+
+        let mut info_hash = None;
+        let mut name = None;
+        let mut trackers = Vec::new();
+        for pair in query.split('&') {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            match key {
+                "xt" if info_hash.is_none() => {
+                    let value = percent_decode(value);
+                    if let Some(hash) = value.strip_prefix("urn:btih:") {
+                        info_hash = Some(
+                            normalise_info_hash(hash)
+                                .ok_or_else(|| MagnetError::InvalidInfoHash(hash.to_string()))?,
+                        );
+                    }
+                }
+                "dn" => name = Some(percent_decode(value).into_owned()),
+                // Some clients number their trackers, eg: `tr.1`.
+                _ if key == "tr" || key.starts_with("tr.") => {
+                    trackers.push(percent_decode(value).into_owned())
+                }
+                _ => {}
+            }
+        }
+
+        // A name without a title still leaves the info-hash and trackers to use.
+        let metadata = name.as_deref().map(|name| self.parse(name));
+        Ok(Magnet {
+            info_hash: info_hash.ok_or(MagnetError::MissingInfoHash)?,
+            name,
+            metadata,
+            trackers,
+        })
+    }
+}
+
+/// Decodes `%XX` escapes and `+` as a space, replacing invalid UTF-8.
+fn percent_decode(value: &str) -> Cow<'_, str> {
+    if !value.contains(['%', '+']) {
+        return Cow::Borrowed(value);
+    }
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' => decoded.push(b' '),
+            b'%' => match bytes.get(i + 1..i + 3).and_then(hex_byte) {
+                Some(byte) => {
+                    decoded.push(byte);
+                    i += 2;
+                }
+                None => decoded.push(b'%'),
+            },
+            b => decoded.push(b),
+        }
+        i += 1;
+    }
+    Cow::Owned(String::from_utf8_lossy(&decoded).into_owned())
+}
+
+fn hex_byte(digits: &[u8]) -> Option<u8> {
+    let text = std::str::from_utf8(digits).ok()?;
+    u8::from_str_radix(text, 16).ok()
+}
+
+/// 40 hex digits, or 32 base32 characters, as lowercase hex.
+fn normalise_info_hash(hash: &str) -> Option<String> {
+    match hash.len() {
+        40 if hash.chars().all(|c| c.is_ascii_hexdigit()) => Some(hash.to_lowercase()),
+        32 => {
+            let mut bits: u64 = 0;
+            let mut count = 0;
+            let mut hex = String::with_capacity(40);
+            for c in hash.chars() {
+                let value = match c.to_ascii_uppercase() {
+                    c @ 'A'..='Z' => c as u64 - 'A' as u64,
+                    c @ '2'..='7' => c as u64 - '2' as u64 + 26,
+                    _ => return None,
+                };
+                bits = (bits << 5) | value;
+                count += 5;
+                if count >= 8 {
+                    count -= 8;
+                    hex.push_str(&format!("{:02x}", (bits >> count) & 0xFF));
+                }
+            }
+            Some(hex)
+        }
+        _ => None,
+    }
+}
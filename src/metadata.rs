@@ -1,25 +1,54 @@
-use crate::error::ErrorMatch;
+use crate::audio::{AudioCodec, AudioFormat};
+use crate::checksum;
+use crate::date::AirDate;
+use crate::edition::Edition;
+use crate::error::{ErrorMatch, Warning};
+use crate::language;
+use crate::parser::Parser;
 use crate::pattern;
 use crate::pattern::Pattern;
-use regex::Captures;
+use crate::service::StreamingService;
+use crate::span::{Field, Span};
+use crate::video::{HdrFormat, Resolution, Source, VideoCodec};
+use regex::{Captures, Match};
 use std::borrow::Cow;
-use std::cmp::{max, min};
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io;
+use std::ops::Range;
+use std::path::{Component, Path};
 
 use std::{convert::TryFrom, str::FromStr};
 
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct Metadata {
-    title: String,
+    title: Option<String>,
+    episode_title: Option<String>,
     season: Option<i32>,
     episode: Option<i32>,
     episodes: Vec<i32>,
+    seasons: Vec<i32>,
+    complete: bool,
+    absolute_episodes: Vec<i32>,
+    batch: bool,
     year: Option<i32>,
+    air_date: Option<AirDate>,
+    part: Option<i32>,
+    disc: Option<i32>,
+    volume: Option<i32>,
     resolution: Option<String>,
     quality: Option<String>,
     codec: Option<String>,
     audio: Option<String>,
+    audio_format: Option<AudioFormat>,
+    hdr: Option<String>,
+    bit_depth: Option<u8>,
+    streaming_service: Option<StreamingService>,
     group: Option<String>,
     country: Option<String>,
+    edition: Option<String>,
     extended: bool,
     hardcoded: bool,
     proper: bool,
@@ -28,51 +57,280 @@ pub struct Metadata {
     unrated: bool,
     three_d: bool,
     imdb: Option<String>,
+    crc32: Option<u32>,
+    size: Option<u64>,
     extension: Option<String>,
+    region: Option<String>,
+    container: Option<String>,
+    language: Option<String>,
+    audio_languages: Vec<String>,
+    subtitle_languages: Vec<String>,
+    website: Option<String>,
+    extra: BTreeMap<String, String>,
+    excess: Vec<String>,
+    spans: Vec<Span>,
+}
+
+/// The part of the name still left for the title, and the matches that bounded it.
+struct TitleBounds<'p> {
+    start: usize,
+    end: usize,
+    start_set_by: Option<Span>,
+    end_set_by: Option<Span>,
+    /// Full matches of every pattern apart from garbage, used to find the excess.
+    matched: Vec<Range<usize>>,
+    /// Values no later match may overlap, eg: an air date, whose dashes are not a group.
+    consumed: Vec<Range<usize>>,
+    /// Decides which fields are looked for at all.
+    parser: &'p Parser,
+}
+
+impl<'p> TitleBounds<'p> {
+    fn new(torrent_name: &str, parser: &'p Parser) -> Self {
+        Self {
+            start: 0,
+            end: torrent_name.len(),
+            start_set_by: None,
+            end_set_by: None,
+            matched: Vec::new(),
+            consumed: Vec::new(),
+            parser,
+        }
+    }
+
+    fn extracts(&self, field: &Field) -> bool {
+        self.parser.extracts(field)
+    }
+
+    fn consume(&mut self, m: Option<Match<'_>>) {
+        self.consumed.extend(m.map(|m| m.range()));
+    }
+
+    fn is_consumed(&self, m: &Match<'_>) -> bool {
+        self.consumed
+            .iter()
+            .any(|r| m.start() < r.end && r.start < m.end())
+    }
+
+    fn is_empty(&self) -> bool {
+        self.start >= self.end
+    }
+
+    fn consumed_by(&self) -> Vec<Span> {
+        let mut spans: Vec<Span> = self
+            .start_set_by
+            .iter()
+            .chain(self.end_set_by.iter())
+            .cloned()
+            .collect();
+        spans.sort_by_key(|s| (s.start(), s.field()));
+        spans
+    }
 }
 
 fn check_pattern_and_extract<'a>(
+    field: Field,
     pattern: &Pattern,
     torrent_name: &'a str,
-    title_start: &mut usize,
-    title_end: &mut usize,
-    extract_value: impl Fn(Captures<'a>) -> Option<&'a str>,
-) -> Option<&'a str> {
-    pattern.captures(torrent_name).and_then(|caps| {
-        if let Some(cap) = caps.get(0) {
-            if pattern.before_title() {
-                *title_start = max(*title_start, cap.end());
-            } else {
-                *title_end = min(*title_end, cap.start());
-            }
-        }
-        extract_value(caps)
-    })
+    title: &mut TitleBounds,
+    extract_value: impl Fn(Captures<'a>) -> Option<Match<'a>>,
+) -> Option<Match<'a>> {
+    check_pattern(field, pattern, torrent_name, title).and_then(extract_value)
 }
 
 fn check_pattern<'a>(
+    field: Field,
+    pattern: &Pattern,
+    torrent_name: &'a str,
+    title: &mut TitleBounds,
+) -> Option<Captures<'a>> {
+    check_pattern_after(field, pattern, torrent_name, 0, title)
+}
+
+/// Like `check_pattern`, but skips matches that start before `title_end`, for tags that
+/// can also be words in a title, eg: `8-Bit.Christmas.2021`.
+///
+/// Matches that overlap a consumed value are always skipped.
+fn check_pattern_after<'a>(
+    field: Field,
+    pattern: &Pattern,
+    torrent_name: &'a str,
+    title_end: usize,
+    title: &mut TitleBounds,
+) -> Option<Captures<'a>> {
+    check_pattern_where(field, pattern, torrent_name, title, |m| {
+        m.start() >= title_end
+    })
+}
+
+/// Like `check_pattern`, but only for a match that `keep` accepts.
+fn check_pattern_where<'a>(
+    field: Field,
     pattern: &Pattern,
     torrent_name: &'a str,
-    title_start: &mut usize,
-    title_end: &mut usize,
+    title: &mut TitleBounds,
+    keep: impl Fn(&Match<'_>) -> bool,
 ) -> Option<Captures<'a>> {
-    pattern.captures(torrent_name).map(|caps| {
-        if let Some(cap) = caps.get(0) {
-            if pattern.before_title() {
-                *title_start = max(*title_start, cap.end());
-            } else {
-                *title_end = min(*title_end, cap.start());
+    if !title.extracts(&field) {
+        return None;
+    }
+    pattern
+        .captures_where(torrent_name, |m| keep(m) && !title.is_consumed(m))
+        .inspect(|caps| record_match(field, pattern, caps, title))
+}
+
+/// Shrinks the title to leave out a match.
+fn record_match(field: Field, pattern: &Pattern, caps: &Captures<'_>, title: &mut TitleBounds) {
+    if let Some(cap) = caps.get(0) {
+        if field != Field::Garbage {
+            title.matched.push(cap.range());
+        }
+        if pattern.before_title() {
+            if cap.end() > title.start {
+                title.start = cap.end();
+                title.start_set_by = Some(Span::new(field, cap.range()));
+            }
+        } else if cap.start() < title.end {
+            title.end = cap.start();
+            title.end_set_by = Some(Span::new(field, cap.range()));
+        }
+    }
+}
+
+/// The number of a part, disc or volume. Before the year it is part of the title, as in
+/// `Kill.Bill.Vol.2.2004`, so only one after the year ends the title.
+fn check_numbered<'a>(
+    field: Field,
+    pattern: &Pattern,
+    caps: Option<Captures<'a>>,
+    after_year: usize,
+    title: &mut TitleBounds,
+) -> Option<Match<'a>> {
+    let caps = caps.filter(|_| title.extracts(&field))?;
+    if caps.get(0)?.start() >= after_year {
+        record_match(field, pattern, &caps, title);
+    }
+    caps.name("number")
+}
+
+/// Whether the inside of a leading `[...]` is a site, eg: `www.UsaBit.com` rather than a group.
+fn is_website(text: &str) -> bool {
+    match text.rsplit_once('.') {
+        Some((host, tld)) => {
+            !host.is_empty()
+                && !text.contains(' ')
+                && (2..=6).contains(&tld.len())
+                && tld.chars().all(|c| c.is_ascii_alphabetic())
+        }
+        None => false,
+    }
+}
+
+/// The parts of `name` outside the title and every recognised field.
+fn excess(name: &str, title: Option<Span>, matched: &[Range<usize>]) -> Vec<Range<usize>> {
+    let mut used = vec![false; name.len()];
+    for range in matched.iter().cloned().chain(title.map(|t| t.range())) {
+        used[range].iter_mut().for_each(|u| *u = true);
+    }
+    let separator = |c: char| c.is_whitespace() || "._-[](),/".contains(c);
+    let mut parts = Vec::new();
+    let mut start = None;
+    for i in 0..=name.len() {
+        match (start, i < name.len() && !used[i]) {
+            (None, true) => start = Some(i),
+            (Some(s), false) => {
+                let part = name[s..i].trim_matches(separator);
+                if !part.is_empty() {
+                    let offset = part.as_ptr() as usize - name.as_ptr() as usize;
+                    parts.push(offset..offset + part.len());
+                }
+                start = None;
             }
+            _ => {}
         }
-        caps
+    }
+    parts
+}
+
+/// The excess right after the episode, before the next field, eg: `Made.You.Look` in
+/// `S01E03.Made.You.Look.1080p`.
+///
+/// Only whole words count, not what is left of a field cut short by the episode.
+fn episode_title(name: &str, spans: &[Span], excess: &[Range<usize>]) -> Option<usize> {
+    let episode = spans.iter().find(|s| s.field() == Field::Episode)?;
+    let is_word_boundary = |c: Option<char>| c.is_none_or(|c| !c.is_alphanumeric());
+    let next = spans
+        .iter()
+        .map(|s| s.start())
+        .filter(|start| *start >= episode.end())
+        .min()
+        .unwrap_or(name.len());
+    excess.iter().position(|part| {
+        part.start >= episode.end()
+            && part.end <= next
+            && is_word_boundary(name[..part.start].chars().next_back())
+            && is_word_boundary(name[part.end..].chars().next())
     })
 }
 
-fn capture_to_string(caps: Option<Captures<'_>>) -> Option<String> {
-    caps.and_then(|c| c.get(0)).map(|m| m.as_str().to_string())
+/// Every season in a pack such as `S01-S05`, `Seasons 1-3` or `S01.S02.S03`.
+fn parse_seasons(text: &str) -> Vec<i32> {
+    let numbers: Vec<i32> = text
+        .split(|c: char| !c.is_ascii_digit())
+        .filter_map(|n| n.parse().ok())
+        .collect();
+    let lower = text.to_lowercase();
+    let is_range = lower.contains('-') || lower.contains('~') || lower.contains("to");
+    match numbers[..] {
+        [first, last] if is_range && first <= last => (first..=last).collect(),
+        _ => {
+            let mut seasons = Vec::new();
+            for n in numbers {
+                if !seasons.contains(&n) {
+                    seasons.push(n);
+                }
+            }
+            seasons
+        }
+    }
+}
+
+/// Bytes in a size such as `1,4 GB`. `KB`, `MB` and `GB` are powers of 1000 and
+/// `KiB`, `MiB` and `GiB` powers of 1024.
+fn parse_size(number: &str, unit: &str) -> Option<u64> {
+    let number: f64 = number.replace(',', ".").parse().ok()?;
+    let unit = unit.to_uppercase();
+    let base: f64 = if unit.contains('I') { 1024.0 } else { 1000.0 };
+    let power = match unit.chars().next()? {
+        'K' => 1,
+        'M' => 2,
+        'G' => 3,
+        'T' => 4,
+        _ => return None,
+    };
+    Some((number * base.powi(power)).round() as u64)
+}
+
+fn match_to_string(m: Option<Match<'_>>) -> Option<String> {
+    m.map(|m| m.as_str().to_string())
+}
+
+fn parse_number(field: Field, m: Option<Match<'_>>, warnings: &mut Vec<Warning>) -> Option<i32> {
+    let m = m?;
+    match m.as_str().parse() {
+        Ok(n) => Some(n),
+        Err(_) => {
+            warnings.push(Warning::NumberOutOfRange {
+                field,
+                value: m.as_str().to_string(),
+            });
+            None
+        }
+    }
 }
 
 impl Metadata {
+    /// Parses a name with the default options, see `Parser` to change them.
     ///```
     /// use torrent_name_parser::Metadata;
     ///
@@ -83,19 +341,87 @@ impl Metadata {
     ///   assert_eq!(m.is_show(), true);
     ///   // Season is not 0 (zero) meaning it is not a Season Special. Eg: Christmas Special
     ///   assert_eq!(m.is_special(), false);
-
     /// }
     ///```
     pub fn from(name: &str) -> Result<Self, ErrorMatch> {
         Metadata::from_str(name)
     }
 
+    /// Parses a name without failing, reporting problems as warnings instead.
+    ///
+    /// Fields that could be read are kept even when there is no title.
+    ///```
+    /// use torrent_name_parser::Metadata;
+    /// use torrent_name_parser::error::Warning;
+    ///
+    /// let (m, warnings) = Metadata::parse_lenient("[Rip Time] S01E12 [1080p]");
+    /// assert_eq!(m.maybe_title(), None);
+    /// assert_eq!(m.episode(), Some(12));
+    /// assert_eq!(m.resolution(), Some("1080p"));
+    /// assert!(matches!(warnings[0], Warning::NoTitle { .. }));
+    ///```
+    pub fn parse_lenient(name: &str) -> (Self, Vec<Warning>) {
+        parse(name, &Parser::default())
+    }
+
+    /// Parses every component of a path and merges them, for files that rely on their
+    /// folders for context, eg: `Show Name/Season 02/03 - Title.mkv`.
+    ///
+    /// The file gives the episode and everything else it names. The nearest folder that
+    /// names them fills in the title, season, year and any other field the file leaves
+    /// out, except the absolute episodes, batch, CRC32, size and extension, which describe
+    /// the folder rather than the file. `spans()` and `excess()` refer to the file name.
+    ///```
+    /// use torrent_name_parser::Metadata;
+    ///
+    /// let m = Metadata::from_path("Show Name/Season 02/03 - Title.mkv").unwrap();
+    /// assert_eq!(m.title(), "Show Name");
+    /// assert_eq!(m.season(), Some(2));
+    /// assert_eq!(m.episode(), Some(3));
+    /// assert_eq!(m.episode_title(), Some("Title"));
+    /// assert_eq!(m.extension(), Some("mkv"));
+    ///```
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self, ErrorMatch> {
+        Parser::default().parse_path(path)
+    }
+
+    /// The title, or `""` if none was found by `parse_lenient`.
     pub fn title(&self) -> &str {
-        &self.title
+        self.title.as_deref().unwrap_or_default()
+    }
+    /// The title, which is only `None` for names parsed by `parse_lenient`.
+    pub fn maybe_title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+    /// The name of the episode, between the episode number and the next field.
+    ///
+    ///```
+    /// use torrent_name_parser::Metadata;
+    ///
+    /// let m = Metadata::from("The EXPANSE - S03 E01 - Fight or Flight (1080p - AMZN Web-DL)")
+    ///     .unwrap();
+    /// assert_eq!(m.title(), "The EXPANSE");
+    /// assert_eq!(m.episode_title(), Some("Fight or Flight"));
+    ///```
+    pub fn episode_title(&self) -> Option<&str> {
+        self.episode_title.as_deref()
     }
     pub fn season(&self) -> Option<i32> {
         self.season
     }
+    /// Every season in a pack, or just `season()`.
+    /// # Examples:
+    /// `S02` -> `[2]`  
+    /// `S01-S03` -> `[1,2,3]`  
+    /// `Seasons 1-3` -> `[1,2,3]`  
+    /// `S01.S02.S04` -> `[1,2,4]`  
+    pub fn seasons(&self) -> &Vec<i32> {
+        &self.seasons
+    }
+    /// Whether this is a complete season or series pack, eg: `Complete Series`.
+    pub fn complete(&self) -> bool {
+        self.complete
+    }
     pub fn episode(&self) -> Option<i32> {
         self.episode
     }
@@ -108,9 +434,56 @@ impl Metadata {
     pub fn episodes(&self) -> &Vec<i32> {
         &self.episodes
     }
+    /// The episode number counted from the start of the series, as used by anime
+    /// fansub releases, eg: `[Group] Show - 1052 [1080p]` -> `1052`.
+    pub fn absolute_episode(&self) -> Option<i32> {
+        self.absolute_episodes.first().copied()
+    }
+    /// Every absolute episode in a batch, eg: `- 01~12` -> `[1, 2, .., 12]`.
+    ///```
+    /// use torrent_name_parser::Metadata;
+    ///
+    /// let m = Metadata::from("[Group] Show - 01-12 (Batch)").unwrap();
+    /// assert_eq!(m.title(), "Show");
+    /// assert_eq!(m.absolute_episodes(), &(1..=12).collect::<Vec<_>>());
+    /// assert_eq!(m.episode(), None);
+    /// assert!(m.batch());
+    ///```
+    pub fn absolute_episodes(&self) -> &Vec<i32> {
+        &self.absolute_episodes
+    }
+    /// Whether this is a batch of several episodes, marked `Batch` or given as a range.
+    pub fn batch(&self) -> bool {
+        self.batch
+    }
     pub fn year(&self) -> Option<i32> {
         self.year
     }
+    /// When a daily show episode was broadcast, read from a date such as `2023.10.05`.
+    pub fn air_date(&self) -> Option<AirDate> {
+        self.air_date
+    }
+    /// The part of a film split across files, eg: `Part.1` or `Pt2`.
+    ///
+    ///```
+    /// use torrent_name_parser::Metadata;
+    ///
+    /// let m = Metadata::from("Movie.1999.Pt2.DVDRip.x264-GRP").unwrap();
+    /// assert_eq!(m.title(), "Movie");
+    /// assert_eq!(m.part(), Some(2));
+    /// assert_eq!(m.season(), None);
+    ///```
+    pub fn part(&self) -> Option<i32> {
+        self.part
+    }
+    /// The disc of a multi-disc release, eg: `CD1` or `Disc 3`.
+    pub fn disc(&self) -> Option<i32> {
+        self.disc
+    }
+    /// The volume, eg: `Vol. 2`, which stays in the title when it comes before the year.
+    pub fn volume(&self) -> Option<i32> {
+        self.volume
+    }
     pub fn resolution(&self) -> Option<&str> {
         self.resolution.as_deref()
     }
@@ -123,6 +496,49 @@ impl Metadata {
     pub fn audio(&self) -> Option<&str> {
         self.audio.as_deref()
     }
+    /// `resolution()` as a comparable `Resolution`, if it is a known one.
+    pub fn video_resolution(&self) -> Option<Resolution> {
+        self.resolution.as_deref().and_then(Resolution::from_tag)
+    }
+    /// `quality()` normalised to a `Source`, eg: `BrRip` and `bluray` are both `Source::BluRay`.
+    pub fn source(&self) -> Option<Source> {
+        self.quality.as_deref().and_then(Source::from_tag)
+    }
+    /// `codec()` normalised to a `VideoCodec`, eg: `x264` and `h.264` are both `VideoCodec::H264`.
+    pub fn video_codec(&self) -> Option<VideoCodec> {
+        self.codec.as_deref().and_then(VideoCodec::from_tag)
+    }
+    /// `audio()` normalised to an `AudioCodec`. Tags that are not codecs, like `Dual Audio`, give `None`.
+    pub fn audio_codec(&self) -> Option<AudioCodec> {
+        self.audio_format.and_then(|f| f.codec())
+    }
+    /// `audio()` broken down into codec, channel layout and Atmos.
+    pub fn audio_format(&self) -> Option<AudioFormat> {
+        self.audio_format
+    }
+    /// The dynamic range tag as written, eg: `HDR`, `DV.HDR10` or `Dolby Vision`.
+    pub fn hdr(&self) -> Option<&str> {
+        self.hdr.as_deref()
+    }
+    /// Every format in `hdr()`, eg: `DV.HDR10` -> `[DolbyVision, Hdr10]`.
+    pub fn hdr_formats(&self) -> Vec<HdrFormat> {
+        self.hdr
+            .as_deref()
+            .map(HdrFormat::from_tag)
+            .unwrap_or_default()
+    }
+    /// Whether the video needs an HDR display or tone mapping.
+    pub fn is_hdr(&self) -> bool {
+        self.hdr_formats().iter().any(|f| *f != HdrFormat::Sdr)
+    }
+    /// Bits per colour channel, eg: `10` for `10bit` or `Hi10P`.
+    pub fn bit_depth(&self) -> Option<u8> {
+        self.bit_depth
+    }
+    /// The service a WEB release came from, eg: `AMZN` -> `StreamingService::Amazon`.
+    pub fn streaming_service(&self) -> Option<StreamingService> {
+        self.streaming_service
+    }
     pub fn group(&self) -> Option<&str> {
         self.group.as_deref()
     }
@@ -132,6 +548,52 @@ impl Metadata {
     pub fn imdb_tag(&self) -> Option<&str> {
         self.imdb.as_deref()
     }
+    /// The edition tag as written, eg: `Directors.Cut` or `IMAX.Edition`. Only tags after
+    /// the year or episode count, so `The.Final.Cut.2004` is a title.
+    pub fn edition(&self) -> Option<&str> {
+        self.edition.as_deref()
+    }
+    /// Every edition in `edition()`, eg: `Criterion.Remastered` -> `[Criterion, Remastered]`.
+    ///
+    ///```
+    /// use torrent_name_parser::{Edition, Metadata};
+    ///
+    /// let m = Metadata::from("Apocalypse.Now.1979.Final.Cut.1080p.BluRay.x264-GRP").unwrap();
+    /// assert_eq!(m.title(), "Apocalypse Now");
+    /// assert_eq!(m.editions(), vec![Edition::FinalCut]);
+    ///```
+    pub fn editions(&self) -> Vec<Edition> {
+        self.edition
+            .as_deref()
+            .map(Edition::from_tag)
+            .unwrap_or_default()
+    }
+    /// The checksum fansub releases put in the name, eg: `[33538C7C]` -> `0x33538C7C`.
+    pub fn crc32(&self) -> Option<u32> {
+        self.crc32
+    }
+    /// Whether the file at `path` matches `crc32()`, or `None` if the name has no checksum.
+    ///
+    /// Reads the whole file, so it can be slow for large episodes.
+    pub fn verify_crc32(&self, path: impl AsRef<Path>) -> io::Result<Option<bool>> {
+        match self.crc32 {
+            Some(expected) => Ok(Some(checksum::crc32(File::open(path)?)? == expected)),
+            None => Ok(None),
+        }
+    }
+    /// The size given in the name in bytes, eg: `750MB` -> `750_000_000`.
+    ///
+    ///```
+    /// use torrent_name_parser::Metadata;
+    ///
+    /// let m = Metadata::from("Lucy 2014 Dual-Audio WEBRip 1400Mb").unwrap();
+    /// assert_eq!(m.size(), Some(1_400_000_000));
+    /// let m = Metadata::from("Movie.2019.1080p.WEB.x264.[1,5 GiB]").unwrap();
+    /// assert_eq!(m.size(), Some(1_610_612_736));
+    ///```
+    pub fn size(&self) -> Option<u64> {
+        self.size
+    }
     pub fn extended(&self) -> bool {
         self.extended
     }
@@ -156,227 +618,761 @@ impl Metadata {
     pub fn extension(&self) -> Option<&str> {
         self.extension.as_deref()
     }
+    /// DVD region code, eg: `R5`.
+    pub fn region(&self) -> Option<&str> {
+        self.region.as_deref()
+    }
+    pub fn container(&self) -> Option<&str> {
+        self.container.as_deref()
+    }
+    /// The language tags as written, eg: `ITA.ENG` or `German.DL`.
+    pub fn language(&self) -> Option<&str> {
+        self.language.as_deref()
+    }
+    /// ISO 639-2 codes of the spoken languages, eg: `ITA.ENG` -> `["ita", "eng"]`.
+    ///
+    /// `MULTi` gives `mul` and `Dubbed` on its own gives `und`.
+    ///```
+    /// use torrent_name_parser::Metadata;
+    ///
+    /// let m = Metadata::from("Le.Film.2019.MULTi.1080p.BluRay.x264-GRP").unwrap();
+    /// assert_eq!(m.audio_languages(), &["mul"]);
+    /// let m = Metadata::from("Le.Film.2019.VOSTFR.1080p.WEB.x264-GRP").unwrap();
+    /// assert_eq!(m.subtitle_languages(), &["fra"]);
+    ///```
+    pub fn audio_languages(&self) -> &[String] {
+        &self.audio_languages
+    }
+    /// ISO 639-2 codes of the subtitle languages, eg: `VOSTFR` -> `["fra"]`.
+    ///
+    /// `Multi-Subs` gives `mul` and `Subbed` on its own gives `und`.
+    pub fn subtitle_languages(&self) -> &[String] {
+        &self.subtitle_languages
+    }
+    /// The site a release was scraped from, taken from a leading `[www.site.com]`.
+    pub fn website(&self) -> Option<&str> {
+        self.website.as_deref()
+    }
+    /// Values found by custom patterns, keyed by the name they were registered under
+    /// with `ParserBuilder::pattern`.
+    pub fn extra(&self) -> &BTreeMap<String, String> {
+        &self.extra
+    }
+    /// Whatever is left of the name once the title and every known field are removed,
+    /// including matches for known garbage such as `3rd Nov`.
+    ///
+    ///```
+    /// use torrent_name_parser::Metadata;
+    ///
+    /// let m = Metadata::from("[ www.UsaBit.com ] - My Cousin Vinny (1992) BluRay 720p 750MB Ganool")
+    ///     .unwrap();
+    /// assert_eq!(m.website(), Some("www.UsaBit.com"));
+    /// assert_eq!(m.excess(), &["Ganool"]);
+    ///```
+    pub fn excess(&self) -> &[String] {
+        &self.excess
+    }
+    /// Where each extracted field was found in the original name, ordered by position.
+    pub fn spans(&self) -> &[Span] {
+        &self.spans
+    }
+    /// The span a single field was read from, if it was found.
+    pub fn span(&self, field: Field) -> Option<Span> {
+        self.spans.iter().find(|s| s.field() == field).cloned()
+    }
+    /// Whether this is an episode, numbered by season or `absolute_episode()`, or dated
+    /// by `air_date()`.
     pub fn is_show(&self) -> bool {
-        self.season.is_some()
+        self.season.is_some() || !self.absolute_episodes.is_empty() || self.air_date.is_some()
     }
     pub fn is_special(&self) -> bool {
         self.season.map(|s| s < 1).unwrap_or(false)
     }
 }
 
-impl FromStr for Metadata {
-    type Err = ErrorMatch;
+pub(crate) fn parse(name: &str, parser: &Parser) -> (Metadata, Vec<Warning>) {
+    let mut warnings = Vec::new();
+    if name.trim().is_empty() {
+        warnings.push(Warning::EmptyInput);
+        return (Metadata::default(), warnings);
+    }
 
-    fn from_str(name: &str) -> Result<Self, Self::Err> {
-        let mut title_start = 0;
-        let mut title_end = name.len();
-        let mut episodes: Vec<i32> = Vec::new();
-        let interim_last_episode;
+    let mut bounds = TitleBounds::new(name, parser);
+    let mut episodes: Vec<i32> = Vec::new();
 
-        let season = check_pattern_and_extract(
-            &pattern::SEASON,
-            name,
-            &mut title_start,
-            &mut title_end,
-            |caps| {
-                caps.name("short")
-                    .or_else(|| caps.name("long"))
-                    .or_else(|| caps.name("dash"))
-                    .or_else(|| caps.name("collection"))
-                    .map(|m| m.as_str())
-            },
-        );
+    // Found first so that `CD1` or `Pt2 x264` are not read as a season or episode.
+    let part_caps = pattern::PART.captures(name);
+    let disc_caps = pattern::DISC.captures(name);
+    let volume_caps = pattern::VOLUME.captures(name);
+    for caps in [&part_caps, &disc_caps, &volume_caps] {
+        if let Some(m) = caps.as_ref().and_then(|caps| caps.get(0)) {
+            // Without the separators around it, which a following field may start with.
+            let separator = |c: char| !c.is_alphanumeric();
+            let start = m.end() - m.as_str().trim_start_matches(separator).len();
+            let end = m.start() + m.as_str().trim_end_matches(separator).len();
+            bounds.consumed.push(start..end);
+        }
+    }
 
-        let episode = check_pattern_and_extract(
-            &pattern::EPISODE,
+    let season =
+        check_pattern_and_extract(Field::Season, &pattern::SEASON, name, &mut bounds, |caps| {
+            caps.name("short")
+                .or_else(|| caps.name("long"))
+                .or_else(|| caps.name("dash"))
+                .or_else(|| caps.name("collection"))
+        });
+    let season_pack = check_pattern_and_extract(
+        Field::Season,
+        &pattern::SEASONS,
+        name,
+        &mut bounds,
+        |caps| caps.name("seasons"),
+    );
+    let mut seasons = season_pack
+        .map(|m| parse_seasons(m.as_str()))
+        .unwrap_or_default();
+    let season_number = parse_number(Field::Season, season, &mut warnings);
+    if seasons.is_empty() {
+        seasons.extend(season_number);
+    }
+    // A bare `COMPLETE` can also be a title word, eg: `COMPLETE.UNKNOWN.2024`, so it has
+    // to follow the season, or at least not start the title.
+    let complete_start = season.or(season_pack).map_or(bounds.start + 1, |m| m.end());
+    let is_bare =
+        |m: &Match<'_>| m.as_str().trim_matches(|c: char| !c.is_alphanumeric()) == "COMPLETE";
+    let complete = check_pattern_where(
+        Field::Complete,
+        &pattern::COMPLETE,
+        name,
+        &mut bounds,
+        |m| !is_bare(m) || m.start() >= complete_start,
+    )
+    .and_then(|caps| caps.name("complete").or_else(|| caps.name("after_season")));
+    // Only now, as `S01-S05` is also part of the match for `S01-S05.Complete`.
+    bounds.consume(season_pack);
+    bounds.consume(complete);
+
+    let episode = check_pattern_and_extract(
+        Field::Episode,
+        &pattern::EPISODE,
+        name,
+        &mut bounds,
+        |caps| {
+            caps.name("short")
+                .or_else(|| caps.name("cross"))
+                .or_else(|| caps.name("dash"))
+        },
+    );
+    // Only look for a last episode if pattern::EPISODE returned a value.
+    if let Some(first_episode) = parse_number(Field::Episode, episode, &mut warnings) {
+        episodes.push(first_episode);
+        let last_episode = check_pattern_and_extract(
+            Field::Episode,
+            &pattern::LAST_EPISODE,
             name,
-            &mut title_start,
-            &mut title_end,
-            |caps| {
-                caps.name("short")
-                    .or_else(|| caps.name("cross"))
-                    .or_else(|| caps.name("dash"))
-                    .map(|m| m.as_str())
-            },
+            &mut bounds,
+            |caps| caps.get(1),
         );
-        // Only look for a last episode if pattern::EPISODE returned a value.
-        if let Some(first_episode) = episode {
-            episodes.push(first_episode.parse().unwrap());
-            interim_last_episode = check_pattern_and_extract(
-                &pattern::LAST_EPISODE,
-                name,
-                &mut title_start,
-                &mut title_end,
-                |caps| caps.get(1).map(|m| m.as_str()),
-            );
-            if let Some(last_episode) = interim_last_episode {
-                // Sanity check that last_episode does not contain a value or 0 (Zero)
-                if last_episode.len() == 1 && last_episode.contains('0') {
-                    // Treat a string ending with '0' (zero) as invalid and skip further work
-                } else {
-                    // Populate Vec with each episode number
-                    for number_of_episode in
-                        first_episode.parse::<i32>().unwrap() + 1..=last_episode.parse().unwrap()
-                    {
-                        episodes.push(number_of_episode);
-                    }
-                }
+        if let Some(last_episode) = parse_number(Field::Episode, last_episode, &mut warnings) {
+            // A last episode of 0 (zero) is invalid, so skip further work
+            if last_episode != 0 {
+                // Populate Vec with each episode number
+                episodes.extend(first_episode + 1..=last_episode);
             }
         }
-        let year = check_pattern_and_extract(
-            &pattern::YEAR,
-            name,
-            &mut title_start,
-            &mut title_end,
-            |caps: Captures<'_>| caps.name("year").map(|m| m.as_str()),
-        );
-
-        let resolution = check_pattern_and_extract(
-            &pattern::RESOLUTION,
-            name,
-            &mut title_start,
-            &mut title_end,
-            |caps| caps.get(1).map(|m| m.as_str()),
-        )
-        .map(String::from);
-        let quality = check_pattern_and_extract(
-            &pattern::QUALITY,
+    }
+    // Fansub releases, eg: `[Group] Show - 1052 [1080p]`, number episodes from the first.
+    let mut absolute_episodes: Vec<i32> = Vec::new();
+    let absolute = if season.is_none() && episode.is_none() && name.starts_with('[') {
+        check_pattern(
+            Field::AbsoluteEpisode,
+            &pattern::ABSOLUTE_EPISODE,
             name,
-            &mut title_start,
-            &mut title_end,
-            |caps| caps.get(0).map(|m| m.as_str()),
+            &mut bounds,
         )
-        .map(String::from);
-        let codec = check_pattern_and_extract(
-            &pattern::CODEC,
-            name,
-            &mut title_start,
-            &mut title_end,
-            |caps| caps.get(0).map(|m| m.as_str()),
+    } else {
+        None
+    };
+    let absolute_episode = absolute.as_ref().and_then(|caps| caps.name("first"));
+    let last_absolute_episode = absolute.as_ref().and_then(|caps| caps.name("last"));
+    if let Some(first) = parse_number(Field::AbsoluteEpisode, absolute_episode, &mut warnings) {
+        absolute_episodes.push(first);
+        if let Some(last) =
+            parse_number(Field::AbsoluteEpisode, last_absolute_episode, &mut warnings)
+        {
+            absolute_episodes.extend(first + 1..=last);
+        }
+    }
+
+    let air_date_caps = check_pattern(Field::AirDate, &pattern::AIR_DATE, name, &mut bounds);
+    let air_date_match = air_date_caps.as_ref().and_then(|caps| caps.name("date"));
+    let air_date = air_date_caps.as_ref().and_then(|caps| {
+        ["ymd", "dmy", "mdy"].iter().find_map(|order| {
+            let part = |unit: &str| caps.name(&format!("{}_{}", order, unit));
+            AirDate::from_parts(
+                part("year")?.as_str(),
+                part("month")?.as_str(),
+                part("day")?.as_str(),
+            )
+        })
+    });
+    bounds.consume(air_date_match.filter(|_| air_date.is_some()));
+    // The year of an air date is not the year of the show, and an absolute episode
+    // can look like a year, so only look before either.
+    let year_end = [air_date_match, absolute_episode]
+        .iter()
+        .flatten()
+        .map(|m| m.start())
+        .min()
+        .unwrap_or(name.len());
+    let year_text = &name[..year_end];
+    let year = if parser.year_in_title {
+        pattern::YEAR
+            .captures(year_text)
+            .filter(|_| bounds.extracts(&Field::Year))
+            .and_then(|caps| caps.name("year"))
+    } else {
+        check_pattern_and_extract(
+            Field::Year,
+            &pattern::YEAR,
+            year_text,
+            &mut bounds,
+            |caps: Captures<'_>| caps.name("year"),
         )
-        .map(String::from);
-        let audio = check_pattern_and_extract(
-            &pattern::AUDIO,
-            name,
-            &mut title_start,
-            &mut title_end,
-            |caps| caps.get(0).map(|m| m.as_str()),
+    };
+    let after_year = year.map_or(0, |m| m.end());
+    let part = check_numbered(
+        Field::Part,
+        &pattern::PART,
+        part_caps,
+        after_year,
+        &mut bounds,
+    );
+    let disc = check_numbered(
+        Field::Disc,
+        &pattern::DISC,
+        disc_caps,
+        after_year,
+        &mut bounds,
+    );
+    let volume = check_numbered(
+        Field::Volume,
+        &pattern::VOLUME,
+        volume_caps,
+        after_year,
+        &mut bounds,
+    );
+    // Where the season, episode, date or year ended the title, if one did.
+    let named_end = Some(bounds.end).filter(|end| *end < name.len());
+    let resolution = check_pattern_and_extract(
+        Field::Resolution,
+        &pattern::RESOLUTION,
+        name,
+        &mut bounds,
+        |caps| caps.get(1),
+    );
+    let quality = check_pattern_and_extract(
+        Field::Quality,
+        &pattern::QUALITY,
+        name,
+        &mut bounds,
+        |caps| caps.get(0),
+    );
+    let codec =
+        check_pattern_and_extract(Field::Codec, &pattern::CODEC, name, &mut bounds, |caps| {
+            caps.get(0)
+        });
+    // Tags that are also words, eg: `Opus`, only count after the title. Without a season,
+    // episode, date or year that is after the first resolution, quality or codec, and
+    // otherwise just past its start.
+    let title_end = if bounds.end < name.len() {
+        bounds.end
+    } else {
+        bounds.start + 1
+    };
+    // Bit depths are rarely words, so only the start of the title needs to be kept free.
+    let tag_end = named_end.unwrap_or(bounds.start + 1);
+    // Like absolute episodes, batches belong to fansub releases, elsewhere `Batch` is
+    // likelier a title word, eg: `Batch.Man.2019`.
+    let batch = if name.starts_with('[') {
+        check_pattern(Field::Batch, &pattern::BATCH, name, &mut bounds)
+    } else {
+        check_pattern_after(Field::Batch, &pattern::BATCH, name, title_end, &mut bounds)
+    }
+    .and_then(|caps| caps.name("batch"));
+    let audio_caps =
+        check_pattern_after(Field::Audio, &pattern::AUDIO, name, title_end, &mut bounds);
+    let audio = audio_caps.as_ref().and_then(|caps| caps.name("audio"));
+    let audio_format = audio_caps.as_ref().and_then(|caps| {
+        AudioFormat::from_parts(
+            caps.name("codec").map(|m| m.as_str()),
+            caps.name("channels").map(|m| m.as_str()),
+            caps.name("atmos")
+                .or_else(|| caps.name("atmos_only"))
+                .is_some(),
         )
-        .map(String::from);
-        let group = check_pattern_and_extract(
+    });
+    let hdr = check_pattern_after(Field::Hdr, &pattern::HDR, name, title_end, &mut bounds)
+        .and_then(|caps| caps.name("hdr"));
+    let bit_depth = check_pattern_after(
+        Field::BitDepth,
+        &pattern::BIT_DEPTH,
+        name,
+        tag_end,
+        &mut bounds,
+    )
+    .and_then(|caps| caps.name("bits").or_else(|| caps.name("hi")));
+    let streaming_service = check_pattern_after(
+        Field::StreamingService,
+        &pattern::STREAMING_SERVICE,
+        name,
+        title_end,
+        &mut bounds,
+    )
+    .and_then(|caps| caps.name("service"));
+    let extension = check_pattern_and_extract(
+        Field::Extension,
+        &pattern::FILE_EXTENSION,
+        name,
+        &mut bounds,
+        |caps| caps.get(1),
+    );
+    // The extension is not part of the group, as in `-GRP.mkv`.
+    let group_text = extension.map_or(name, |m| &name[..m.start() - 1]);
+    // In `Episode 30 - Historia` what follows the dash names the episode, not a group.
+    let names_episode = |m: Match<'_>| {
+        episode.is_some_and(|e| e.end() <= m.start() && name[e.end()..m.start()].trim().is_empty())
+            && m.as_str().starts_with("- ")
+    };
+    let group = if pattern::GROUP
+        .captures(group_text)
+        .and_then(|caps| caps.get(0))
+        .is_some_and(names_episode)
+    {
+        None
+    } else {
+        check_pattern_and_extract(
+            Field::Group,
             &pattern::GROUP,
-            name,
-            &mut title_start,
-            &mut title_end,
-            |caps| caps.get(2).map(|m| m.as_str()),
-        )
-        .map(String::from);
-        let imdb = check_pattern_and_extract(
-            &pattern::IMDB,
-            name,
-            &mut title_start,
-            &mut title_end,
-            |caps| caps.get(0).map(|m| m.as_str()),
-        )
-        .map(String::from);
-        let extension = check_pattern_and_extract(
-            &pattern::FILE_EXTENSION,
-            name,
-            &mut title_start,
-            &mut title_end,
-            |caps| caps.get(1).map(|m| m.as_str()),
-        )
-        .map(String::from);
-        let country = check_pattern_and_extract(
-            &pattern::COUNTRY,
-            name,
-            &mut title_start,
-            &mut title_end,
-            |caps| caps.name("country").map(|m| m.as_str()),
+            group_text,
+            &mut bounds,
+            |caps| caps.get(2),
         )
-        .map(String::from);
-
-        let extended = check_pattern(&pattern::EXTENDED, name, &mut title_start, &mut title_end);
-        let hardcoded = check_pattern(&pattern::HARDCODED, name, &mut title_start, &mut title_end);
-        let proper = check_pattern(&pattern::PROPER, name, &mut title_start, &mut title_end);
-        let repack = check_pattern(&pattern::REPACK, name, &mut title_start, &mut title_end);
-        let widescreen =
-            check_pattern(&pattern::WIDESCREEN, name, &mut title_start, &mut title_end);
-        let unrated = check_pattern(&pattern::UNRATED, name, &mut title_start, &mut title_end);
-        let three_d = check_pattern(&pattern::THREE_D, name, &mut title_start, &mut title_end);
-
-        let region = check_pattern(&pattern::REGION, name, &mut title_start, &mut title_end);
-        let container = check_pattern(&pattern::CONTAINER, name, &mut title_start, &mut title_end);
-        let language = check_pattern(&pattern::LANGUAGE, name, &mut title_start, &mut title_end);
-        let garbage = check_pattern(&pattern::GARBAGE, name, &mut title_start, &mut title_end);
-        let website = check_pattern(&pattern::WEBSITE, name, &mut title_start, &mut title_end);
-
-        if title_start >= title_end {
-            return Err(ErrorMatch::new(vec![
-                ("season", season.map(String::from)),
-                ("episode", episode.map(String::from)),
-                ("year", year.map(String::from)),
-                ("extension", extension.map(String::from)),
-                ("resolution", resolution),
-                ("quality", quality),
-                ("codec", codec),
-                ("audio", audio),
-                ("group", group),
-                ("country", country),
-                ("imdb", imdb),
-                ("extended", capture_to_string(extended)),
-                ("proper", capture_to_string(proper)),
-                ("repack", capture_to_string(repack)),
-                ("widescreen", capture_to_string(widescreen)),
-                ("unrated", capture_to_string(unrated)),
-                ("three_d", capture_to_string(three_d)),
-                ("region", capture_to_string(region)),
-                ("container", capture_to_string(container)),
-                ("language", capture_to_string(language)),
-                ("garbage", capture_to_string(garbage)),
-                ("website", capture_to_string(website)),
-            ]));
+    };
+    let imdb = check_pattern_and_extract(Field::Imdb, &pattern::IMDB, name, &mut bounds, |caps| {
+        caps.get(0)
+    });
+    let crc32 =
+        check_pattern_and_extract(Field::Crc32, &pattern::CRC32, name, &mut bounds, |caps| {
+            caps.name("crc32")
+        });
+    let size_caps = check_pattern(Field::Size, &pattern::SIZE, name, &mut bounds);
+    let size = size_caps.as_ref().and_then(|caps| caps.name("size"));
+    let size_bytes = size_caps
+        .as_ref()
+        .and_then(|caps| parse_size(caps.name("number")?.as_str(), caps.name("unit")?.as_str()));
+    let country = check_pattern_and_extract(
+        Field::Country,
+        &pattern::COUNTRY,
+        name,
+        &mut bounds,
+        |caps| caps.name("country"),
+    );
+    let edition = check_pattern_after(
+        Field::Edition,
+        &pattern::EDITION,
+        name,
+        title_end,
+        &mut bounds,
+    )
+    .and_then(|caps| caps.name("edition"));
+
+    let flag = |field, pattern: &Pattern, bounds: &mut TitleBounds| {
+        check_pattern_and_extract(field, pattern, name, bounds, |caps| caps.get(0))
+    };
+    let extended = flag(Field::Extended, &pattern::EXTENDED, &mut bounds);
+    let hardcoded = flag(Field::Hardcoded, &pattern::HARDCODED, &mut bounds);
+    let proper = flag(Field::Proper, &pattern::PROPER, &mut bounds);
+    let repack = flag(Field::Repack, &pattern::REPACK, &mut bounds);
+    let widescreen = flag(Field::Widescreen, &pattern::WIDESCREEN, &mut bounds);
+    let unrated = flag(Field::Unrated, &pattern::UNRATED, &mut bounds);
+    let three_d = flag(Field::ThreeD, &pattern::THREE_D, &mut bounds);
+
+    let region = flag(Field::Region, &pattern::REGION, &mut bounds);
+    let container = flag(Field::Container, &pattern::CONTAINER, &mut bounds);
+    let language = check_pattern_after(
+        Field::Language,
+        &pattern::LANGUAGE,
+        name,
+        title_end,
+        &mut bounds,
+    )
+    .and_then(|caps| caps.name("language"));
+    let (audio_languages, subtitle_languages) = language
+        .map(|m| language::audio_and_subtitles(m.as_str()))
+        .unwrap_or_default();
+    let garbage = flag(Field::Garbage, &pattern::GARBAGE, &mut bounds);
+    let website = check_pattern_and_extract(
+        Field::Website,
+        &pattern::WEBSITE,
+        name,
+        &mut bounds,
+        |caps| caps.get(2).filter(|m| is_website(m.as_str())),
+    );
+
+    let mut extra = BTreeMap::new();
+    let mut extra_spans = Vec::new();
+    for (key, pattern) in &parser.patterns {
+        let field = Field::Extra(key.clone());
+        if let Some(caps) = check_pattern(field.clone(), pattern, name, &mut bounds) {
+            if let Some(m) = caps.name("value").or_else(|| caps.get(0)) {
+                extra.insert(key.clone(), m.as_str().to_string());
+                extra_spans.push(Span::new(field, m.range()));
+            }
+        }
+    }
+
+    let mut spans: Vec<Span> = [
+        (Field::Season, season.or(season_pack)),
+        (Field::Complete, complete),
+        (Field::Episode, episode),
+        (Field::AbsoluteEpisode, absolute_episode),
+        (Field::Batch, batch),
+        (Field::Year, year),
+        (Field::Part, part),
+        (Field::Disc, disc),
+        (Field::Volume, volume),
+        (
+            Field::AirDate,
+            air_date_match.filter(|_| air_date.is_some()),
+        ),
+        (Field::Resolution, resolution),
+        (Field::Quality, quality),
+        (Field::Codec, codec),
+        (Field::Audio, audio),
+        (Field::Hdr, hdr),
+        (Field::BitDepth, bit_depth),
+        (Field::StreamingService, streaming_service),
+        (Field::Group, group),
+        (Field::Country, country),
+        (Field::Imdb, imdb),
+        (Field::Crc32, crc32),
+        (Field::Size, size),
+        (Field::Extension, extension),
+        (Field::Edition, edition),
+        (Field::Extended, extended),
+        (Field::Hardcoded, hardcoded),
+        (Field::Proper, proper),
+        (Field::Repack, repack),
+        (Field::Widescreen, widescreen),
+        (Field::Unrated, unrated),
+        (Field::ThreeD, three_d),
+        (Field::Region, region),
+        (Field::Container, container),
+        (Field::Language, language),
+        (Field::Garbage, garbage),
+        (Field::Website, website),
+    ]
+    .iter()
+    .filter_map(|(field, m)| m.map(|m| Span::new(field.clone(), m.range())))
+    .chain(extra_spans)
+    .collect();
+
+    let mut title = None;
+    if !bounds.is_empty() {
+        let mut raw = &name[bounds.start..bounds.end];
+        if let Some(pos) = raw.find('(') {
+            raw = raw.split_at(pos).0;
         }
+        raw = raw.trim_start_matches(" -");
+        raw = raw.trim_end_matches(" -");
 
-        let mut title = &name[title_start..title_end];
-        if let Some(pos) = title.find('(') {
-            title = title.split_at(pos).0;
+        // `raw` is still a slice of `name` here, so its offset is the title span.
+        let dotted = !raw.contains(' ') && raw.contains('.');
+        let trimmed =
+            raw.trim_matches(|c: char| c.is_whitespace() || c == '_' || (dotted && c == '.'));
+        let offset = trimmed.as_ptr() as usize - name.as_ptr() as usize;
+        if !trimmed.is_empty() {
+            spans.push(Span::new(Field::Title, offset..offset + trimmed.len()));
         }
-        title = title.trim_start_matches(" -");
-        title = title.trim_end_matches(" -");
-        let title = match !title.contains(' ') && title.contains('.') {
-            true => Cow::Owned(title.replace('.', " ")),
-            false => Cow::Borrowed(title),
+
+        let cleaned = if parser.replace_separators {
+            let raw = match dotted {
+                true => Cow::Owned(raw.replace('.', " ")),
+                false => Cow::Borrowed(raw),
+            };
+            raw.replace('_', " ")
+                .replacen('(', "", 1)
+                .replacen("- ", "", 1)
+                .trim()
+                .to_string()
+        } else {
+            trimmed.to_string()
         };
-        let title = title
-            .replace('_', " ")
-            .replacen('(', "", 1)
-            .replacen("- ", "", 1)
-            .trim()
-            .to_string();
-
-        Ok(Metadata {
-            title,
-            season: season.map(|s| s.parse().unwrap()),
-            episode: episode.map(|s| s.parse().unwrap()),
-            episodes,
-            year: year.map(|s| s.parse().unwrap()),
-            resolution,
-            quality,
-            codec,
-            audio,
-            group,
-            country,
-            extended: extended.is_some(),
-            hardcoded: hardcoded.is_some(),
-            proper: proper.is_some(),
-            repack: repack.is_some(),
-            widescreen: widescreen.is_some(),
-            unrated: unrated.is_some(),
-            three_d: three_d.is_some(),
-            imdb,
-            extension,
+        if !cleaned.is_empty() {
+            title = Some(cleaned);
+        }
+    }
+    spans.sort_by_key(|s| (s.start(), s.field()));
+    let mut excess = excess(
+        name,
+        spans.iter().find(|s| s.field() == Field::Title).cloned(),
+        &bounds.matched,
+    );
+    // What follows the episode of a pack is a tag, not the title of an episode.
+    let pack = complete.is_some() || seasons.len() > 1 || episodes.len() > 2;
+    let episode_title = episode_title(name, &spans, &excess)
+        .filter(|_| !pack && parser.extracts(&Field::EpisodeTitle))
+        .map(|i| {
+            let range = excess.remove(i);
+            spans.push(Span::new(Field::EpisodeTitle, range.clone()));
+            let raw = &name[range];
+            match (parser.replace_separators, raw.contains(' ')) {
+                (false, _) => raw.to_string(),
+                (true, true) => raw.replace('_', " "),
+                (true, false) => raw.replace(['.', '_'], " "),
+            }
+        });
+    if seasons.is_empty() && !absolute_episodes.is_empty() {
+        seasons.extend(parser.default_season);
+    }
+    spans.sort_by_key(|s| (s.start(), s.field()));
+    let excess = excess.into_iter().map(|r| name[r].to_string()).collect();
+
+    let metadata = Metadata {
+        title,
+        episode_title,
+        season: season_number.or_else(|| seasons.first().copied()),
+        seasons,
+        complete: complete.is_some(),
+        episode: episodes.first().copied(),
+        episodes,
+        batch: batch.is_some() || absolute_episodes.len() > 1,
+        absolute_episodes,
+        year: parse_number(Field::Year, year, &mut warnings),
+        air_date,
+        part: parse_number(Field::Part, part, &mut warnings),
+        disc: parse_number(Field::Disc, disc, &mut warnings),
+        volume: parse_number(Field::Volume, volume, &mut warnings),
+        resolution: match_to_string(resolution),
+        quality: match_to_string(quality),
+        codec: match_to_string(codec),
+        audio: match_to_string(audio),
+        audio_format,
+        hdr: match_to_string(hdr),
+        bit_depth: bit_depth.and_then(|m| m.as_str().parse().ok()),
+        streaming_service: streaming_service.and_then(|m| StreamingService::from_tag(m.as_str())),
+        group: match_to_string(group),
+        country: match_to_string(country),
+        edition: match_to_string(edition),
+        extended: extended.is_some(),
+        hardcoded: hardcoded.is_some(),
+        proper: proper.is_some(),
+        repack: repack.is_some(),
+        widescreen: widescreen.is_some(),
+        unrated: unrated.is_some(),
+        three_d: three_d.is_some(),
+        imdb: match_to_string(imdb),
+        size: size_bytes,
+        crc32: crc32.and_then(|m| u32::from_str_radix(m.as_str(), 16).ok()),
+        extension: match_to_string(extension),
+        region: match_to_string(region),
+        container: match_to_string(container),
+        language: match_to_string(language),
+        audio_languages,
+        subtitle_languages,
+        website: match_to_string(website),
+        extra,
+        excess,
+        spans,
+    };
+
+    if metadata.title.is_none() {
+        warnings.push(Warning::NoTitle {
+            consumed_by: bounds.consumed_by(),
+        });
+    }
+    (metadata, warnings)
+}
+
+/// Also returns the file name, which `spans()` and any `NoTitle` refer to.
+pub(crate) fn parse_path(path: &Path, parser: &Parser) -> (String, Metadata, Vec<Warning>) {
+    let mut components: Vec<Cow<str>> = path
+        .components()
+        .filter_map(|c| match c {
+            Component::Normal(c) => Some(c.to_string_lossy()),
+            _ => None,
         })
+        .collect();
+    let file = match components.pop() {
+        Some(file) => file.into_owned(),
+        None => {
+            let (metadata, warnings) = parse("", parser);
+            return (String::new(), metadata, warnings);
+        }
+    };
+    let folders: Vec<Metadata> = components
+        .iter()
+        .rev()
+        .map(|folder| parse(folder, parser).0)
+        .collect();
+    let in_season = folders.iter().any(|f| f.season.is_some());
+
+    let (mut metadata, mut warnings) = parse(&file, parser);
+    if in_season && metadata.season.is_none() && metadata.episode.is_none() {
+        if let Some((episode, episode_warnings)) = parse_leading_episode(&file, parser) {
+            metadata = episode;
+            warnings = episode_warnings;
+        }
+    }
+    let no_title = warnings
+        .iter()
+        .position(|w| matches!(w, Warning::NoTitle { .. }))
+        .map(|i| warnings.remove(i));
+
+    if metadata.title.is_none() {
+        metadata.title = folders.iter().find_map(|f| f.title.clone());
+    }
+    for folder in &folders {
+        metadata.fill_from(folder);
+    }
+    if metadata.title.is_none() {
+        warnings.push(no_title.unwrap_or(Warning::NoTitle {
+            consumed_by: Vec::new(),
+        }));
+    }
+    (file, metadata, warnings)
+}
+
+/// A file numbered by its folder's season, eg: `03 - Title.mkv`, where what follows the
+/// number is the episode title.
+fn parse_leading_episode(file: &str, parser: &Parser) -> Option<(Metadata, Vec<Warning>)> {
+    let caps = pattern::LEADING_EPISODE.captures(file)?;
+    let number = caps.name("episode")?;
+    let mut warnings = Vec::new();
+    let episode = parse_number(Field::Episode, Some(number), &mut warnings)?;
+
+    let mut metadata = match caps.name("rest") {
+        Some(rest) => {
+            let (mut metadata, rest_warnings) = parse(rest.as_str(), parser);
+            // Its title is the episode title, the show's comes from a folder.
+            warnings.extend(
+                rest_warnings
+                    .into_iter()
+                    .filter(|w| !matches!(w, Warning::NoTitle { .. })),
+            );
+            for span in metadata.spans.iter_mut() {
+                *span = Span::new(
+                    span.field(),
+                    span.start() + rest.start()..span.end() + rest.start(),
+                );
+            }
+            if metadata.episode_title.is_none() {
+                metadata.episode_title = metadata.title.take();
+            }
+            if let Some(span) = metadata
+                .spans
+                .iter_mut()
+                .find(|s| s.field() == Field::Title)
+            {
+                *span = Span::new(Field::EpisodeTitle, span.range());
+            }
+            metadata
+        }
+        None => Metadata {
+            extension: match_to_string(caps.name("extension")),
+            spans: caps
+                .name("extension")
+                .map(|m| Span::new(Field::Extension, m.range()))
+                .into_iter()
+                .collect(),
+            ..Metadata::default()
+        },
+    };
+    metadata.episode = Some(episode);
+    metadata.episodes = vec![episode];
+    metadata
+        .spans
+        .push(Span::new(Field::Episode, number.range()));
+    metadata.spans.sort_by_key(|s| (s.start(), s.field()));
+    Some((metadata, warnings))
+}
+
+impl Metadata {
+    /// Takes whatever this is missing from the name of a folder it was found in.
+    fn fill_from(&mut self, folder: &Metadata) {
+        if self.season.is_none() {
+            self.season = folder.season;
+            self.seasons = folder.seasons.clone();
+        }
+        self.complete |= folder.complete;
+        self.year = self.year.or(folder.year);
+        self.air_date = self.air_date.or(folder.air_date);
+        self.part = self.part.or(folder.part);
+        self.disc = self.disc.or(folder.disc);
+        self.volume = self.volume.or(folder.volume);
+        self.resolution = self.resolution.take().or_else(|| folder.resolution.clone());
+        self.quality = self.quality.take().or_else(|| folder.quality.clone());
+        self.codec = self.codec.take().or_else(|| folder.codec.clone());
+        if self.audio.is_none() {
+            self.audio = folder.audio.clone();
+            self.audio_format = folder.audio_format;
+        }
+        self.hdr = self.hdr.take().or_else(|| folder.hdr.clone());
+        self.bit_depth = self.bit_depth.or(folder.bit_depth);
+        self.streaming_service = self.streaming_service.or(folder.streaming_service);
+        self.group = self.group.take().or_else(|| folder.group.clone());
+        self.country = self.country.take().or_else(|| folder.country.clone());
+        self.edition = self.edition.take().or_else(|| folder.edition.clone());
+        self.extended |= folder.extended;
+        self.hardcoded |= folder.hardcoded;
+        self.proper |= folder.proper;
+        self.repack |= folder.repack;
+        self.widescreen |= folder.widescreen;
+        self.unrated |= folder.unrated;
+        self.three_d |= folder.three_d;
+        self.imdb = self.imdb.take().or_else(|| folder.imdb.clone());
+        self.region = self.region.take().or_else(|| folder.region.clone());
+        self.container = self.container.take().or_else(|| folder.container.clone());
+        if self.language.is_none() {
+            self.language = folder.language.clone();
+            self.audio_languages = folder.audio_languages.clone();
+            self.subtitle_languages = folder.subtitle_languages.clone();
+        }
+        self.website = self.website.take().or_else(|| folder.website.clone());
+        for (key, value) in &folder.extra {
+            self.extra
+                .entry(key.clone())
+                .or_insert_with(|| value.clone());
+        }
+    }
+}
+
+/// The first warning as an error, or the metadata if there were none.
+pub(crate) fn into_result(
+    name: &str,
+    metadata: Metadata,
+    mut warnings: Vec<Warning>,
+) -> Result<Metadata, ErrorMatch> {
+    if warnings.is_empty() {
+        return Ok(metadata);
+    }
+    Err(match warnings.remove(0) {
+        Warning::EmptyInput => ErrorMatch::EmptyInput,
+        Warning::NumberOutOfRange { field, value } => ErrorMatch::NumberOutOfRange { field, value },
+        Warning::NoTitle { consumed_by } => ErrorMatch::NoTitle {
+            name: name.to_string(),
+            partial: Box::new(metadata),
+            consumed_by,
+        },
+    })
+}
+
+impl FromStr for Metadata {
+    type Err = ErrorMatch;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        Parser::default().parse(name)
     }
 }
 
@@ -0,0 +1,162 @@
+use crate::error::{ErrorMatch, Warning};
+use crate::metadata::{self, Metadata};
+use crate::pattern::Pattern;
+use crate::span::Field;
+use std::path::Path;
+
+/// A parser with options, for when the defaults of `Metadata::from` don't fit.
+///
+///```
+/// use torrent_name_parser::{Field, Parser};
+///
+/// let parser = Parser::builder()
+///     .fields(&[Field::Season, Field::Episode])
+///     .replace_separators(false)
+///     .build();
+/// let m = parser.parse("The.Walking.Dead.S05E03.720p.HDTV.x264-ASAP").unwrap();
+/// assert_eq!(m.title(), "The.Walking.Dead");
+/// assert_eq!(m.episode(), Some(3));
+/// assert_eq!(m.resolution(), None);
+///```
+#[derive(Clone, Debug)]
+pub struct Parser {
+    pub(crate) fields: Option<Vec<Field>>,
+    pub(crate) replace_separators: bool,
+    pub(crate) year_in_title: bool,
+    pub(crate) default_season: Option<i32>,
+    pub(crate) strict: bool,
+    pub(crate) patterns: Vec<(String, Pattern)>,
+}
+
+impl Default for Parser {
+    /// The options `Metadata::from` uses.
+    fn default() -> Self {
+        Self {
+            fields: None,
+            replace_separators: true,
+            year_in_title: false,
+            default_season: None,
+            strict: true,
+            patterns: Vec::new(),
+        }
+    }
+}
+
+impl Parser {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn builder() -> ParserBuilder {
+        ParserBuilder::default()
+    }
+
+    /// Parses a name, failing on any problem when strict, or only on an empty name otherwise.
+    pub fn parse(&self, name: &str) -> Result<Metadata, ErrorMatch> {
+        let (metadata, warnings) = self.parse_lenient(name);
+        self.to_result(name, metadata, warnings)
+    }
+
+    /// Parses a name without failing, like `Metadata::parse_lenient`.
+    pub fn parse_lenient(&self, name: &str) -> (Metadata, Vec<Warning>) {
+        metadata::parse(name, self)
+    }
+
+    /// Parses a path with folders for context, like `Metadata::from_path`.
+    pub fn parse_path(&self, path: impl AsRef<Path>) -> Result<Metadata, ErrorMatch> {
+        let (file, metadata, warnings) = metadata::parse_path(path.as_ref(), self);
+        self.to_result(&file, metadata, warnings)
+    }
+
+    fn to_result(
+        &self,
+        name: &str,
+        metadata: Metadata,
+        mut warnings: Vec<Warning>,
+    ) -> Result<Metadata, ErrorMatch> {
+        if !self.strict {
+            warnings.retain(|w| *w == Warning::EmptyInput);
+        }
+        metadata::into_result(name, metadata, warnings)
+    }
+
+    pub(crate) fn extracts(&self, field: &Field) -> bool {
+        match &self.fields {
+            Some(fields) => {
+                matches!(field, Field::Title | Field::Extra(_)) || fields.contains(field)
+            }
+            None => true,
+        }
+    }
+}
+
+/// Sets up a `Parser`, starting from the defaults of `Metadata::from`.
+#[derive(Clone, Debug, Default)]
+pub struct ParserBuilder {
+    parser: Parser,
+}
+
+impl ParserBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only look for these fields, leaving the rest of the name to the title. The title is
+    /// always extracted.
+    pub fn fields(mut self, fields: &[Field]) -> Self {
+        self.parser.fields = Some(fields.to_vec());
+        self
+    }
+
+    /// Whether dots and underscores in the title become spaces, on by default.
+    pub fn replace_separators(mut self, replace: bool) -> Self {
+        self.parser.replace_separators = replace;
+        self
+    }
+
+    /// Whether the year stays in the title, eg: `Doctor Who 2005`, while still being read
+    /// as the year. Off by default, where the year ends the title.
+    pub fn year_in_title(mut self, allow: bool) -> Self {
+        self.parser.year_in_title = allow;
+        self
+    }
+
+    /// The season of releases numbered by absolute episode, eg: `[Group] Show - 05`, which
+    /// otherwise have none.
+    pub fn default_season(mut self, season: i32) -> Self {
+        self.parser.default_season = Some(season);
+        self
+    }
+
+    /// Whether `Parser::parse` fails when there is no title or a number is out of range,
+    /// on by default. Empty names always fail.
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.parser.strict = strict;
+        self
+    }
+
+    /// Looks for a custom field after the built-in ones, replacing any earlier pattern of
+    /// the same name. What it captures, the `value` group if there is one or else the
+    /// whole match, goes in `Metadata::extra` under `name`, and is left out of the title.
+    ///
+    ///```
+    /// use torrent_name_parser::regex::Regex;
+    /// use torrent_name_parser::{Parser, Pattern};
+    ///
+    /// let internal = Pattern::new(Regex::new(r"\[(?P<value>INTERNAL-[A-Z]+)\]").unwrap(), false, false, false);
+    /// let parser = Parser::builder().pattern("team", internal).build();
+    /// let m = parser.parse("Movie.2019.1080p.WEB.x264 [INTERNAL-TEAM]").unwrap();
+    /// assert_eq!(m.extra()["team"], "INTERNAL-TEAM");
+    /// assert_eq!(m.title(), "Movie");
+    ///```
+    pub fn pattern(mut self, name: impl Into<String>, pattern: Pattern) -> Self {
+        let name = name.into();
+        self.parser.patterns.retain(|(n, _)| *n != name);
+        self.parser.patterns.push((name, pattern));
+        self
+    }
+
+    pub fn build(self) -> Parser {
+        self.parser
+    }
+}
@@ -1,7 +1,9 @@
-use regex::{Captures, Regex};
+use regex::{Captures, Match, Regex};
 use std::iter::Iterator;
 
-#[derive(Debug)]
+/// A regex and how its matches bound the title, for the built-in fields and for
+/// custom fields registered with `ParserBuilder::pattern`.
+#[derive(Clone, Debug)]
 pub struct Pattern {
     regex: Regex,
     before_title: bool,
@@ -25,6 +27,9 @@ macro_rules! regex {
 }
 
 impl Pattern {
+    /// `before_title` makes a match mark the start of the title rather than its end,
+    /// `capture_last` takes the last match instead of the first, and
+    /// `no_numbers_surrounding` skips matches next to a digit.
     pub fn new(
         regex: Regex,
         before_title: bool,
@@ -39,8 +44,19 @@ impl Pattern {
         }
     }
     pub fn captures<'t>(&self, text: &'t str) -> Option<Captures<'t>> {
+        self.captures_where(text, |_| true)
+    }
+
+    /// Like `captures`, but only for a match that `keep` accepts.
+    pub(crate) fn captures_where<'t>(
+        &self,
+        text: &'t str,
+        keep: impl Fn(&Match) -> bool,
+    ) -> Option<Captures<'t>> {
         let mut it = self.regex.captures_iter(text).filter(|cap| {
-            if self.no_numbers_surrounding {
+            if !keep(&cap.get(0).unwrap()) {
+                false
+            } else if self.no_numbers_surrounding {
                 let mat = cap.get(0).unwrap();
 
                 let start = mat.start();
@@ -52,7 +68,7 @@ impl Pattern {
                     }
 
                     let prev_char = text[prev..].chars().next().unwrap();
-                    if prev_char.is_digit(10) {
+                    if prev_char.is_ascii_digit() {
                         return false;
                     }
                 }
@@ -65,7 +81,7 @@ impl Pattern {
                         next += 1;
                     }
                     let next_char = text[next..].chars().next().unwrap();
-                    if next_char.is_digit(10) {
+                    if next_char.is_ascii_digit() {
                         return false;
                     }
                 }
@@ -91,24 +107,60 @@ lazy_static! {
     pub static ref SEASON: Pattern = regex!(
         r"(?i)s?(?P<short>\d+) ?[ex]|(?:season)(?:[^\d]|$)(?P<long>\d+)|s(?P<dash>\d+) - \d+|\.s(?P<collection>\d){1,2}\."
     );
+    pub static ref SEASONS: Pattern = regex!(concat!(
+        r"(?i)(?:^|[^a-z0-9])(?P<seasons>",
+        r"s\d{1,2}(?:[~\-]s?\d{1,2}|[ .]?(?:-|~|to)[ .]?s\d{1,2})",
+        r"|seasons?[ .]?\d{1,2}[ .]?(?:-|~|to)[ .]?\d{1,2}",
+        r"|s\d{1,2}(?:[ .]s\d{1,2})+",
+        r")(?:[^a-z0-9]|$)"
+    ));
+    pub static ref COMPLETE: Pattern = regex!(concat!(
+        r"(?:^|[^A-Za-z0-9])(?:(?P<complete>COMPLETE|(?i:complete)[ .\-_](?i:series|seasons?|collection|pack))",
+        r"|(?i:s\d{1,2}|seasons?[ .]?\d{1,2}(?:[ .]?[\-~][ .]?\d{1,2})?)[ .\-_](?P<after_season>(?i:complete)))",
+        r"(?:[^A-Za-z0-9]|$)"
+    ));
     pub static ref EPISODE: Pattern = regex!(
         r"(?i)(?:e|episode)[^.\d]?(?P<short>\d{1,3})|\d+x(?P<cross>\d+)|s\d+ - (?P<dash>\d+)"
     );
     pub static ref LAST_EPISODE: Pattern = regex!(r"(?i)(?:e)(?:\d+)(?:[- ]+)?(?:e(?P<last>\d+))+");
+    pub static ref ABSOLUTE_EPISODE: Pattern = regex!(
+        r"\s-\s(?P<first>\d{1,4})(?:v\d)?(?:\s?[~\-]\s?(?P<last>\d{1,4})(?:v\d)?)?(?:[^0-9A-Za-z]|$)"
+    );
+    pub static ref BATCH: Pattern = regex!(r"(?i)(?:^|[^a-z0-9])(?P<batch>batch)(?:[^a-z0-9]|$)");
+    pub static ref LEADING_EPISODE: Pattern = regex!(
+        r"^(?P<episode>\d{1,3})(?:\.(?P<extension>[A-Za-z0-9]{2,4})$|(?:[ ._]*-[ ._]*|[ ._]+)(?P<rest>.+)$|$)"
+    );
     pub static ref FILE_EXTENSION: Pattern =
         regex!(r"(?i)(?:\.)(?P<extension>[a-z]{2,4}(?:\d)?|m4v|3gp|h26[45])$");
     pub static ref RESOLUTION: Pattern = regex!(r"((\d{3,4}p))[^M]");
     pub static ref QUALITY: Pattern = regex!(
-        r"(?:PPV\.)?[HP]DTV|(?:HD)?CAM|B[rR]Rip|TS|(?:PPV )?WEB-?(DL)?(?: DVDRip)?|H[dD]Rip|DVDRip|DVDRiP|DVDRIP|CamRip|W[EB]B[rR]ip|[Bb]lu[Rr]ay|DvDScr|hdtv"
+        r"(?:PPV\.)?[HP]DTV|(?:HD)?CAM|B[rR]Rip|TS|W[EB]B[rR]ip|(?:PPV )?WEB-?(DL)?(?: DVDRip)?|H[dD]Rip|DVDRip|DVDRiP|DVDRIP|CamRip|[Bb]lu[Rr]ay|DvDScr|hdtv"
     );
     pub static ref CODEC: Pattern = regex!(r"(?i)xvid|x264|h\.?264/?|x265|h\.?265|hevc?");
-    pub static ref AUDIO: Pattern =
-        regex!(r"MP3|DD5\.?1|Dual[\- ]Audio|LiNE|DTS|AAC(?:\.?2\.0)?|AC3(?:\.5\.1)?");
+    pub static ref AUDIO: Pattern = regex!(
+        r"(?i)(?:^|[^a-z0-9])(?P<audio>(?P<codec>MP3|DDP|DD\+|E-?AC-?3|DD|AC-?3|TrueHD|DTS[ .\-]?HD(?:[ .\-]?MA)?|DTS[ .\-]?X|DTS|AAC|FLAC|(?-i:Opus|OPUS))(?:[ .]?(?P<channels>[1-9]\.[0-2]|[1-9][0-2]))?(?:[ .\-]?(?P<atmos>(?-i:Atmos|ATMOS)))?|(?P<atmos_only>(?-i:Atmos|ATMOS))|Dual[\- ]Audio|(?-i:LiNE))(?:[^a-z0-9]|$)"
+    );
+    pub static ref HDR: Pattern = regex!(
+        r"(?i)(?:^|[^a-z0-9])(?P<hdr>(?:DV|DoVi|Dolby[ .]?Vision|HDR10(?:\+|Plus)?|HDR|HLG|SDR)(?:[ .\-](?:DV|DoVi|Dolby[ .]?Vision|HDR10(?:\+|Plus)?|HDR|HLG))*)(?:[^a-z0-9+]|$)"
+    );
+    pub static ref BIT_DEPTH: Pattern = regex!(r"(?i)\b(?:(?P<bits>8|10|12)bits?|hi(?P<hi>10)p)\b");
+    pub static ref STREAMING_SERVICE: Pattern = regex!(
+        r"(?:^|[^A-Za-z0-9])(?P<service>AMZN|NF|DSNP|HMAX|ATVP|HULU|PCOK|iT|CRAV|STAN|PMTP|iP)(?:[^A-Za-z0-9]|$)"
+    );
     pub static ref GROUP: Pattern = regex!(r"(- ?([^ -]+(?:-=\{[^ -]+-?$)?))$");
     pub static ref COUNTRY: Pattern = regex!(
         r"\W[(]?(?P<country>(?:U(?:A|G|K|M|S|Y|Z)|(?:A(?:D|E|F|G|I|L|M|N|O|R|S|T|Q|U|W|X|Z))|(?:B(?:A|B|D|E|F|G|H|I|J|L|M|N|O|R|S|T|V|W|Y|Z))|(?:C(?:A|C|D|F|G|H|I|K|L|M|N|O|R|U|V|X|Y|Z))|(?:D(?:E|J|K|M|O|Z))|(?:E(C|E|G|H|R|S|T))|(?:F(?:I|J|K|M|O|R))|(?:G(?:A|B|D|E|F|G|H|I|L|M|N|P|Q|R|S|T|U|W|Y))|(?:H(?:K|M|N|R|T|U))|(?:I(D|E|Q|L|M|N|O|R|S|T))|(?:J(?:E|M|O|P))|(?:K(E|G|H|I|M|N|P|R|W|Y|Z))|(?:L(?:A|B|C|I|K|R|S|T|U|V|Y))|(?:M(?:A|C|D|E|F|G|H|K|L|M|N|O|Q|P|R|S|T|U|V|W|X|Y|Z))|(?:N(?:A|C|E|F|G|I|L|O|P|R|U|Z))|(?:OM)|(?:P(?:A|E|F|G|H|K|L|M|N|R|S|T|W|Y))|(?:QA)|(?:R(?:E|O|S|U|W))|(?:S(?:A|B|C|D|E|G|H|I|J|K|L|M|N|O|R|T|V|Y|Z))|(?:T(?:C|D|F|G|H|J|K|L|M|N|O|R|T|V|W|Z))|(?:V(?:A|C|E|G|I|N|U))|(?:W(F|S))|(?:Y(E|T))|(?:Z(?:A|M|W)))|(?:u(?:a|g|k|m|s|y|z)|(?:a(?:d|e|f|g|i|l|m|n|o|r|s|t|q|u|w|x|z))|(?:b(?:a|b|d|e|f|g|h|i|j|l|m|n|o|r|s|t|v|w|y|z))|(?:c(?:a|c|d|f|g|h|i|k|l|m|n|o|r|u|v|x|y|z))|(?:d(?:e|j|k|m|o|z))|(?:e(c|e|g|h|r|s|t))|(?:f(?:i|j|k|m|o|r))|(?:g(?:a|b|d|e|f|g|h|i|l|m|n|p|q|r|s|t|u|w|y))|(?:h(?:k|m|n|r|t|u))|(?:i(d|e|q|l|m|n|o|r|s|t))|(?:j(?:e|m|o|p))|(?:k(e|g|h|i|m|n|p|r|w|y|z))|(?:l(?:a|b|c|i|k|r|s|t|u|v|y))|(?:m(?:a|c|d|e|f|g|h|k|l|m|n|o|q|p|r|s|t|u|v|w|x|y|z))|(?:n(?:a|c|e|f|g|i|l|o|p|r|u|z))|(?:om)|(?:p(?:a|e|f|g|h|k|l|m|n|r|s|t|w|y))|(?:qa)|(?:r(?:e|o|s|u|w))|(?:s(?:a|b|c|d|e|g|h|i|j|k|l|m|n|o|r|t|v|y|z))|(?:t(?:c|d|f|g|h|j|k|l|m|n|o|r|t|v|w|z))|(?:v(?:a|c|e|g|i|n|u))|(?:w(f|s))|(?:y(e|t))|(?:z(?:a|m|w))))[)]?\.S\d"
     );
-    pub static ref REGION: Pattern = regex!(r"R\d");
+    pub static ref EDITION: Pattern = regex!(concat!(
+        r"(?i)(?:^|[^a-z0-9])(?P<edition>",
+        r"(?:Director'?s[ .\-]?Cut|Theatrical(?:[ .\-](?:Cut|Edition))?|Remaster(?:ed)?|Criterion(?:[ .\-]Collection)?",
+        r"|IMAX(?:[ .\-]Edition)?|Special[ .\-]Edition|(?:The[ .\-])?Final[ .\-]Cut|Ultimate[ .\-](?:Edition|Cut)",
+        r"|(?:\d{1,3}(?:st|nd|rd|th)[ .\-])?Anniversary(?:[ .\-]Edition)?|Open[ .\-]Matte)",
+        r"(?:[ .\-]+(?:Director'?s[ .\-]?Cut|Theatrical|Remaster(?:ed)?|Criterion(?:[ .\-]Collection)?|IMAX",
+        r"|Special[ .\-]Edition|Final[ .\-]Cut|Ultimate[ .\-](?:Edition|Cut)|Anniversary(?:[ .\-]Edition)?|Open[ .\-]Matte))*",
+        r")(?:[^a-z0-9]|$)"
+    ));
+    pub static ref REGION: Pattern = regex!(r"\bR[0-9]\b");
     pub static ref EXTENDED: Pattern = regex!(r"EXTENDED");
     pub static ref HARDCODED: Pattern = regex!(r"HC");
     pub static ref PROPER: Pattern = regex!(r"PROPER");
@@ -117,9 +169,35 @@ lazy_static! {
     pub static ref WIDESCREEN: Pattern = regex!(r"WS");
     pub static ref THREE_D: Pattern = regex!(r"3D");
     pub static ref UNRATED: Pattern = regex!(r"UNRATED");
-    pub static ref LANGUAGE: Pattern = regex!(r"rus\.eng|US");
-    pub static ref GARBAGE: Pattern = regex!(r"1400Mb|3rd Nov|((Rip)) ");
+    pub static ref LANGUAGE: Pattern = regex!(concat!(
+        r"(?:^|[^A-Za-z0-9])(?P<language>",
+        r"(?:(?i:multi(?:[ .\-]?subs?)?|vostfr|vost|subfrench|truefrench|vff|vfq|subbed|dubbed|esubs?|dual[ .\-]audio)",
+        r"|ENG|FRE|FRA|GER|DEU|ITA|SPA|ESP|RUS|JAP|JPN|POR|DUT|NLD|KOR|CHI|HIN|POL|SWE|NOR|DAN|FIN|TUR|ARA|HUN|CZE|VF",
+        r"|eng|fre|ger|ita|rus|jpn",
+        r"|ENGLISH|FRENCH|GERMAN|ITALIAN|SPANISH|RUSSIAN|JAPANESE|PORTUGUESE|DUTCH|KOREAN|CHINESE|HINDI|POLISH",
+        r"|(?:English|French|German|Italian|Spanish|Russian|Japanese|Portuguese|Dutch|Korean|Chinese|Hindi|Polish)[ .\-_](?:DL|Dubbed|Subbed|Subs?)\b)",
+        r"(?:[ .\-_]+(?:(?i:multi(?:[ .\-]?subs?)?|vostfr|vost|subfrench|truefrench|vff|vfq|subbed|dubbed|esubs?|subs?)|DL|VF|ENG|FRE|FRA|GER|DEU|ITA|SPA|ESP|RUS|JAP|JPN|POR|DUT|NLD|KOR|CHI|HIN|POL|eng|fre|ger|ita|rus|jpn))*",
+        r")(?:[^A-Za-z0-9]|$)"
+    ));
+    pub static ref SIZE: Pattern = regex!(
+        r"(?i)(?:^|[^a-z0-9])(?P<size>(?P<number>\d+(?:[.,]\d+)?) ?(?P<unit>[KMGT]i?B))(?:[^a-z0-9]|$)"
+    );
+    pub static ref GARBAGE: Pattern = regex!(r"3rd Nov|[A-Za-z](Rip) ");
+    pub static ref CRC32: Pattern = regex!(r"[ \])_.]\[(?P<crc32>[0-9A-Fa-f]{8})\]");
     pub static ref IMDB: Pattern = regex!(r"tt\d{7}");
+    pub static ref AIR_DATE: Pattern = regex!(concat!(
+        r"(?:^|[^0-9])(?P<date>",
+        r"(?P<ymd_year>(?:19|20)\d\d)[.\-_ ](?P<ymd_month>0[1-9]|1[0-2])[.\-_ ](?P<ymd_day>0[1-9]|[12]\d|3[01])",
+        r"|(?P<dmy_day>0[1-9]|[12]\d|3[01])[.\-](?P<dmy_month>0[1-9]|1[0-2])[.\-](?P<dmy_year>(?:19|20)\d\d)",
+        r"|(?P<mdy_month>0[1-9]|1[0-2])[.\-](?P<mdy_day>0[1-9]|[12]\d|3[01])[.\-](?P<mdy_year>\d\d)",
+        r")(?:[^0-9]|$)"
+    ));
+    pub static ref PART: Pattern =
+        regex!(r"(?i)(?:^|[^a-z0-9])(?:part|pt)[ .\-_]?(?P<number>\d{1,2})(?:[^a-z0-9]|$)");
+    pub static ref DISC: Pattern =
+        regex!(r"(?i)(?:^|[^a-z0-9])(?:cd|dis[ck])[ .\-_]?(?P<number>\d{1,2})(?:[^a-z0-9]|$)");
+    pub static ref VOLUME: Pattern =
+        regex!(r"(?i)(?:^|[^a-z0-9])vol(?:ume)?\.?[ .\-_]?(?P<number>\d{1,3})(?:[^a-z0-9]|$)");
     pub static ref YEAR: Pattern = regex!(r"(?P<year>(1[89]|20)\d\d)", false, true, true);
     pub static ref WEBSITE: Pattern = regex!(r"^(\[ ?([^\]]+?) ?\]) ?", true, false, false);
 }
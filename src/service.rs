@@ -0,0 +1,63 @@
+use std::fmt;
+
+/// The streaming service a WEB release was captured from.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StreamingService {
+    Amazon,
+    Netflix,
+    DisneyPlus,
+    HboMax,
+    AppleTvPlus,
+    Hulu,
+    Peacock,
+    ITunes,
+    Crave,
+    Stan,
+    ParamountPlus,
+    BbcIplayer,
+}
+
+impl StreamingService {
+    pub(crate) fn from_tag(tag: &str) -> Option<Self> {
+        match tag {
+            "AMZN" => Some(StreamingService::Amazon),
+            "NF" => Some(StreamingService::Netflix),
+            "DSNP" => Some(StreamingService::DisneyPlus),
+            "HMAX" => Some(StreamingService::HboMax),
+            "ATVP" => Some(StreamingService::AppleTvPlus),
+            "HULU" => Some(StreamingService::Hulu),
+            "PCOK" => Some(StreamingService::Peacock),
+            "iT" => Some(StreamingService::ITunes),
+            "CRAV" => Some(StreamingService::Crave),
+            "STAN" => Some(StreamingService::Stan),
+            "PMTP" => Some(StreamingService::ParamountPlus),
+            "iP" => Some(StreamingService::BbcIplayer),
+            _ => None,
+        }
+    }
+
+    /// The tag used in release names, eg: `AMZN`.
+    pub fn tag(&self) -> &'static str {
+        match self {
+            StreamingService::Amazon => "AMZN",
+            StreamingService::Netflix => "NF",
+            StreamingService::DisneyPlus => "DSNP",
+            StreamingService::HboMax => "HMAX",
+            StreamingService::AppleTvPlus => "ATVP",
+            StreamingService::Hulu => "HULU",
+            StreamingService::Peacock => "PCOK",
+            StreamingService::ITunes => "iT",
+            StreamingService::Crave => "CRAV",
+            StreamingService::Stan => "STAN",
+            StreamingService::ParamountPlus => "PMTP",
+            StreamingService::BbcIplayer => "iP",
+        }
+    }
+}
+
+impl fmt::Display for StreamingService {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.tag())
+    }
+}
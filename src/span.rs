@@ -0,0 +1,93 @@
+use std::ops::Range;
+
+/// A field that `Metadata` extracts from a torrent name.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Field {
+    Title,
+    EpisodeTitle,
+    Season,
+    Episode,
+    AbsoluteEpisode,
+    Batch,
+    Complete,
+    Year,
+    AirDate,
+    Part,
+    Disc,
+    Volume,
+    Resolution,
+    Quality,
+    Codec,
+    Audio,
+    Hdr,
+    BitDepth,
+    StreamingService,
+    Group,
+    Country,
+    Imdb,
+    Crc32,
+    Size,
+    Extension,
+    Edition,
+    Extended,
+    Hardcoded,
+    Proper,
+    Repack,
+    Widescreen,
+    Unrated,
+    ThreeD,
+    Region,
+    Container,
+    Language,
+    Garbage,
+    Website,
+    /// A custom pattern by the name it was registered under, see `Metadata::extra`.
+    Extra(String),
+}
+
+/// The byte range in the original torrent name a `Field` was read from.
+///
+///```
+/// use torrent_name_parser::{Field, Metadata};
+///
+/// let name = "narcos.s01e10.1080p.bluray.x264-rovers";
+/// let m = Metadata::from(name).unwrap();
+/// let span = m.span(Field::Resolution).unwrap();
+/// assert_eq!(&name[span.range()], "1080p");
+/// assert_eq!(span.as_str(name), "1080p");
+///```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Span {
+    field: Field,
+    start: usize,
+    end: usize,
+}
+
+impl Span {
+    pub fn new(field: Field, range: Range<usize>) -> Self {
+        Self {
+            field,
+            start: range.start,
+            end: range.end,
+        }
+    }
+
+    pub fn field(&self) -> Field {
+        self.field.clone()
+    }
+    pub fn start(&self) -> usize {
+        self.start
+    }
+    pub fn end(&self) -> usize {
+        self.end
+    }
+    pub fn range(&self) -> Range<usize> {
+        self.start..self.end
+    }
+    /// The text of the span in `name`, which must be the string that was parsed.
+    pub fn as_str<'a>(&self, name: &'a str) -> &'a str {
+        &name[self.range()]
+    }
+}
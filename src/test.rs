@@ -380,8 +380,11 @@ fn names() {
     assert_eq!(m.title(), "Yes Day");
     assert_eq!(m.extension(), None);
 
-    // FIXME
-    Metadata::from("[Rip Time] Mushoku Tensei_ Jobless Reincarnation - 12 [1080p]").unwrap_err();
+    let m =
+        Metadata::from("[Rip Time] Mushoku Tensei_ Jobless Reincarnation - 12 [1080p]").unwrap();
+    assert_eq!(m.title(), "Mushoku Tensei  Jobless Reincarnation");
+    assert_eq!(m.absolute_episode(), Some(12));
+    assert_eq!(m.resolution(), Some("1080p"));
 
     let m = Metadata::from(
         "Pokémon the Movie - Black - Victini and Reshiram (2011) [x265 Bluray-1080p] [tt1961324]",
@@ -437,6 +440,7 @@ mod extensions {
 }
 
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
 mod special {
     use crate::metadata::Metadata;
 
@@ -459,6 +463,7 @@ mod special {
 }
 
 #[cfg(test)]
+#[allow(clippy::len_zero, clippy::explicit_counter_loop)]
 mod multi_episodes {
     use super::*;
 
@@ -703,3 +708,2018 @@ fn unicode() {
     Metadata::from("é2000").unwrap();
     Metadata::from("é2000é").unwrap();
 }
+
+#[cfg(test)]
+mod spans {
+    use crate::metadata::Metadata;
+    use crate::span::Field;
+
+    #[test]
+    fn spans_point_into_name() {
+        let name = "Hercules (2014) 1080p BrRip H264 - YIFY";
+        let m = Metadata::from(name).unwrap();
+        let text = |field| m.span(field).map(|s| s.as_str(name));
+        assert_eq!(text(Field::Title), Some("Hercules"));
+        assert_eq!(text(Field::Year), Some("2014"));
+        assert_eq!(text(Field::Resolution), Some("1080p"));
+        assert_eq!(text(Field::Quality), Some("BrRip"));
+        assert_eq!(text(Field::Codec), Some("H264"));
+        assert_eq!(text(Field::Group), Some("YIFY"));
+        assert_eq!(text(Field::Season), None);
+    }
+
+    #[test]
+    fn spans_for_show() {
+        let name = "Euphoria.US.S01E03.Made.You.Look.1080p.AMZN.WEB-DL.DDP5.1.H.264-KiNGS.mkv";
+        let m = Metadata::from(name).unwrap();
+        let text = |field| m.span(field).map(|s| s.as_str(name));
+        assert_eq!(text(Field::Title), Some("Euphoria"));
+        assert_eq!(text(Field::Country), Some("US"));
+        assert_eq!(text(Field::Season), Some("01"));
+        assert_eq!(text(Field::Episode), Some("03"));
+        assert_eq!(text(Field::Extension), Some("mkv"));
+    }
+
+    #[test]
+    fn spans_are_ordered() {
+        let m =
+            Metadata::from("[ www.UsaBit.com ] - My Cousin Vinny (1992) BluRay 720p 750MB Ganool")
+                .unwrap();
+        let fields: Vec<Field> = m.spans().iter().map(|s| s.field()).collect();
+        assert_eq!(
+            fields,
+            vec![
+                Field::Website,
+                Field::Title,
+                Field::Year,
+                Field::Quality,
+                Field::Resolution,
+                Field::Size
+            ]
+        );
+    }
+
+    #[test]
+    fn splice_replacement() {
+        let name = "narcos.s01e10.1080p.bluray.x264-rovers";
+        let m = Metadata::from(name).unwrap();
+        let span = m.span(Field::Episode).unwrap();
+        let mut renamed = name.to_string();
+        renamed.replace_range(span.range(), "11");
+        assert_eq!(renamed, "narcos.s01e11.1080p.bluray.x264-rovers");
+    }
+}
+
+#[cfg(test)]
+mod typed {
+    use crate::audio::AudioCodec;
+    use crate::metadata::Metadata;
+    use crate::video::{Resolution, Source, VideoCodec};
+
+    #[test]
+    fn normalises_raw_tags() {
+        let m = Metadata::from("Hercules (2014) 1080p BrRip H264 - YIFY").unwrap();
+        assert_eq!(m.video_resolution(), Some(Resolution::P1080));
+        assert_eq!(m.source(), Some(Source::BluRay));
+        assert_eq!(m.video_codec(), Some(VideoCodec::H264));
+
+        let m = Metadata::from("narcos.s01e10.1080p.bluray.x264-rovers").unwrap();
+        assert_eq!(m.source(), Some(Source::BluRay));
+        assert_eq!(m.video_codec(), Some(VideoCodec::H264));
+
+        let m = Metadata::from("The.Walking.Dead.S05E03.1080p.WEB-DL.DD5.1.H.264-Cyphanix[rartv]")
+            .unwrap();
+        assert_eq!(m.source(), Some(Source::WebDl));
+        assert_eq!(m.video_codec(), Some(VideoCodec::H264));
+        assert_eq!(m.audio_codec(), Some(AudioCodec::DolbyDigital));
+
+        let m = Metadata::from("Rome.S01E11.The.Spoils.BluRay.10Bit.1080p.Dts.H265-d3g").unwrap();
+        assert_eq!(m.video_codec(), Some(VideoCodec::H265));
+
+        let m = Metadata::from("Annabelle.2014.HC.HDRip.XViD.AC3-juggs[ETRG]").unwrap();
+        assert_eq!(m.source(), Some(Source::HdRip));
+        assert_eq!(m.video_codec(), Some(VideoCodec::Xvid));
+        assert_eq!(m.audio_codec(), Some(AudioCodec::DolbyDigital));
+
+        let m = Metadata::from("Guardians Of The Galaxy 2014 R6 720p HDCAM x264-JYK").unwrap();
+        assert_eq!(m.video_resolution(), Some(Resolution::P720));
+        assert_eq!(m.source(), Some(Source::Cam));
+
+        let m = Metadata::from("These.Final.Hours.2013.WBBRip XViD").unwrap();
+        assert_eq!(m.source(), Some(Source::WebRip));
+    }
+
+    #[test]
+    fn web_sources() {
+        let m = Metadata::from("Movie.2019.1080p.WEBRip.x264").unwrap();
+        assert_eq!(m.quality(), Some("WEBRip"));
+        assert_eq!(m.source(), Some(Source::WebRip));
+        assert!(m.excess().is_empty());
+
+        let m = Metadata::from("Movie.2019.1080p.WEB-DL.x264").unwrap();
+        assert_eq!(m.source(), Some(Source::WebDl));
+
+        let m = Metadata::from("Movie.2019.1080p.WEB.x264").unwrap();
+        assert_eq!(m.quality(), Some("WEB"));
+        assert_eq!(m.source(), Some(Source::WebDl));
+        assert!(Source::WebDl > Source::WebRip);
+    }
+
+    #[test]
+    fn non_codec_audio() {
+        let m = Metadata::from("Lucy 2014 Dual-Audio WEBRip 1400Mb").unwrap();
+        assert_eq!(m.audio(), Some("Dual-Audio"));
+        assert_eq!(m.audio_codec(), None);
+    }
+
+    #[test]
+    fn ordering() {
+        assert!(Resolution::P2160 > Resolution::P1080);
+        assert!(Resolution::P720 > Resolution::P480);
+        assert!(Source::BluRay > Source::WebDl);
+        assert!(Source::WebDl > Source::Cam);
+        assert!(VideoCodec::H265 > VideoCodec::H264);
+        assert!(AudioCodec::Dts > AudioCodec::Aac);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Resolution::P1080.to_string(), "1080p");
+        assert_eq!(Source::WebDl.to_string(), "WEB-DL");
+        assert_eq!(VideoCodec::H265.to_string(), "H.265");
+        assert_eq!(AudioCodec::DolbyDigital.to_string(), "DD");
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod serde_round_trip {
+    use crate::error::ErrorMatch;
+    use crate::metadata::Metadata;
+    use regex::Regex;
+
+    /// Every name parsed elsewhere in this file.
+    fn corpus() -> Vec<String> {
+        let calls =
+            Regex::new(r#"(?:Metadata::from|parse_lenient)\(\s*"((?:[^"\\]|\\.)*)""#).unwrap();
+        calls
+            .captures_iter(include_str!("test.rs"))
+            .map(|caps| caps[1].replace("\\\"", "\"").replace("\\\\", "\\"))
+            .collect()
+    }
+
+    #[test]
+    fn metadata_round_trip() {
+        let names = corpus();
+        assert!(names.len() > 100);
+        for name in &names {
+            let (m, _) = Metadata::parse_lenient(name);
+            let json = serde_json::to_string(&m).unwrap();
+            let back: Metadata = serde_json::from_str(&json).unwrap();
+            assert_eq!(m, back, "{}", name);
+        }
+    }
+
+    #[test]
+    fn missing_fields() {
+        let m: Metadata = serde_json::from_str(r#"{"title":"Hercules","year":2014}"#).unwrap();
+        assert_eq!(m.title(), "Hercules");
+        assert_eq!(m.year(), Some(2014));
+        assert!(m.excess().is_empty());
+        assert!(m.spans().is_empty());
+    }
+
+    #[test]
+    fn metadata_field_names() {
+        let m = Metadata::from("Hercules (2014) 1080p BrRip H264 - YIFY").unwrap();
+        let json = serde_json::to_value(&m).unwrap();
+        assert_eq!(json["title"], "Hercules");
+        assert_eq!(json["year"], 2014);
+        assert_eq!(json["resolution"], "1080p");
+        assert_eq!(json["quality"], "BrRip");
+        assert_eq!(json["codec"], "H264");
+        assert_eq!(json["group"], "YIFY");
+        assert_eq!(json["season"], serde_json::Value::Null);
+    }
+
+    #[test]
+    fn error_round_trip() {
+        let e = Metadata::from("[Rip Time] S01E12 [1080p]").unwrap_err();
+        let json = serde_json::to_string(&e).unwrap();
+        let back: ErrorMatch = serde_json::from_str(&json).unwrap();
+        assert_eq!(e, back);
+    }
+
+    #[test]
+    fn error_variants_round_trip() {
+        for e in [
+            Metadata::from("").unwrap_err(),
+            Metadata::from("S999999999999E01.avi").unwrap_err(),
+        ] {
+            let json = serde_json::to_string(&e).unwrap();
+            let back: ErrorMatch = serde_json::from_str(&json).unwrap();
+            assert_eq!(e, back);
+        }
+    }
+}
+
+#[cfg(test)]
+mod errors {
+    use crate::error::ErrorMatch;
+    use crate::metadata::Metadata;
+    use crate::span::{Field, Span};
+
+    #[test]
+    fn empty_input() {
+        assert_eq!(Metadata::from("").unwrap_err(), ErrorMatch::EmptyInput);
+        assert_eq!(Metadata::from("  \t").unwrap_err(), ErrorMatch::EmptyInput);
+    }
+
+    #[test]
+    fn no_title() {
+        let name = "[Rip Time] S01E12 [1080p]";
+        let e = Metadata::from(name).unwrap_err();
+        let partial = e.partial().unwrap();
+        assert_eq!(partial.title(), "");
+        assert_eq!(partial.episode(), Some(12));
+        assert_eq!(partial.resolution(), Some("1080p"));
+        let consumed: Vec<(Field, &str)> = e
+            .consumed_by()
+            .iter()
+            .map(|s| (s.field(), s.as_str(name)))
+            .collect();
+        assert_eq!(
+            consumed,
+            vec![(Field::Website, "[Rip Time] "), (Field::Season, "S01E")]
+        );
+        assert_eq!(
+            e.to_string(),
+            format!(
+                "couldn't find a title in {:?}, consumed by Website \"[Rip Time] \" and Season \"S01E\"",
+                name
+            )
+        );
+    }
+
+    #[test]
+    fn span_outside_name() {
+        let e = ErrorMatch::NoTitle {
+            name: "x".to_string(),
+            partial: Box::default(),
+            consumed_by: vec![Span::new(Field::Year, 1..5)],
+        };
+        assert_eq!(
+            e.to_string(),
+            "couldn't find a title in \"x\", consumed by Year at 1..5"
+        );
+    }
+
+    #[test]
+    fn no_title_left_by_year() {
+        let e = Metadata::from("(2014)").unwrap_err();
+        assert_eq!(e.partial().unwrap().year(), Some(2014));
+        assert_eq!(e.consumed_by()[0].field(), Field::Year);
+    }
+
+    #[test]
+    fn number_out_of_range() {
+        assert_eq!(
+            Metadata::from("Show.S999999999999E01.avi").unwrap_err(),
+            ErrorMatch::NumberOutOfRange {
+                field: Field::Season,
+                value: "999999999999".to_string()
+            }
+        );
+        assert!(Metadata::from("Show.S999999999999E01.avi")
+            .unwrap_err()
+            .partial()
+            .is_none());
+    }
+}
+
+#[cfg(test)]
+mod lenient {
+    use crate::error::Warning;
+    use crate::metadata::Metadata;
+    use crate::span::Field;
+
+    #[test]
+    fn keeps_fields_without_title() {
+        let (m, warnings) = Metadata::parse_lenient("[Rip Time] S01E12 [1080p]");
+        assert_eq!(m.maybe_title(), None);
+        assert_eq!(m.title(), "");
+        assert_eq!(m.season(), Some(1));
+        assert_eq!(m.episode(), Some(12));
+        assert_eq!(m.resolution(), Some("1080p"));
+        assert_eq!(warnings.len(), 1);
+        assert!(matches!(warnings[0], Warning::NoTitle { .. }));
+    }
+
+    #[test]
+    fn same_as_strict_when_valid() {
+        let name = "Euphoria.US.S01E03.Made.You.Look.1080p.AMZN.WEB-DL.DDP5.1.H.264-KiNGS";
+        let (m, warnings) = Metadata::parse_lenient(name);
+        assert!(warnings.is_empty());
+        assert_eq!(m.maybe_title(), Some("Euphoria"));
+        assert_eq!(m, Metadata::from(name).unwrap());
+    }
+
+    #[test]
+    fn empty_input() {
+        let (m, warnings) = Metadata::parse_lenient("");
+        assert_eq!(m, Metadata::default());
+        assert_eq!(warnings, vec![Warning::EmptyInput]);
+    }
+
+    #[test]
+    fn skips_numbers_out_of_range() {
+        let (m, warnings) = Metadata::parse_lenient("Show.S999999999999E02.720p.avi");
+        assert_eq!(m.title(), "Show");
+        assert_eq!(m.season(), None);
+        assert_eq!(m.episode(), Some(2));
+        assert_eq!(m.resolution(), Some("720p"));
+        assert_eq!(
+            warnings,
+            vec![Warning::NumberOutOfRange {
+                field: Field::Season,
+                value: "999999999999".to_string()
+            }]
+        );
+    }
+}
+
+#[cfg(test)]
+mod discarded_fields {
+    use crate::metadata::Metadata;
+
+    #[test]
+    fn website() {
+        let m = Metadata::from(
+            "[TorrentCounter.to].Pacific.Rim.2.Uprising.2018.1080p.HC.HDRip.x264.[2GB]",
+        )
+        .unwrap();
+        assert_eq!(m.website(), Some("TorrentCounter.to"));
+        assert_eq!(m.title(), "Pacific Rim 2 Uprising");
+    }
+
+    #[test]
+    fn group_in_brackets_is_not_a_website() {
+        let m = Metadata::from("[SubsPlease] Dr. Stone S2 - 07 (1080p) [33538C7C]").unwrap();
+        assert_eq!(m.website(), None);
+        assert_eq!(m.title(), "Dr. Stone");
+    }
+
+    #[test]
+    fn region() {
+        let m = Metadata::from("Brave.2012.R5.DVDRip.XViD.LiNE-UNiQUE").unwrap();
+        assert_eq!(m.region(), Some("R5"));
+        let m = Metadata::from("Guardians Of The Galaxy 2014 R6 720p HDCAM x264-JYK").unwrap();
+        assert_eq!(m.region(), Some("R6"));
+        let m = Metadata::from("Movie.2019.2160p.UHD.BluRay.x265.HDR10.DTS-HD.MA.5.1-GRP").unwrap();
+        assert_eq!(m.region(), None);
+        assert_eq!(m.hdr(), Some("HDR10"));
+    }
+
+    #[test]
+    fn container() {
+        let m = Metadata::from("Movie.2010.720p.BluRay.MKV.x264-GRP").unwrap();
+        assert_eq!(m.container(), Some("MKV"));
+        assert_eq!(m.title(), "Movie");
+    }
+
+    #[test]
+    fn language() {
+        let m = Metadata::from("Movie.2010.rus.eng.720p.BluRay.x264-GRP").unwrap();
+        assert_eq!(m.language(), Some("rus.eng"));
+    }
+
+    #[test]
+    fn excess() {
+        let m = Metadata::from(
+            "[TorrentCounter.to].Pacific.Rim.2.Uprising.2018.1080p.HC.HDRip.x264.[2GB]",
+        )
+        .unwrap();
+        assert!(m.excess().is_empty());
+
+        let m =
+            Metadata::from("[ www.UsaBit.com ] - My Cousin Vinny (1992) BluRay 720p 750MB Ganool")
+                .unwrap();
+        assert_eq!(m.excess(), &["Ganool"]);
+
+        let m =
+            Metadata::from("Euphoria.US.S01E03.Made.You.Look.1080p.AMZN.WEB-DL.DDP5.1.H.264-KiNGS")
+                .unwrap();
+        assert!(m.excess().is_empty());
+
+        let m = Metadata::from("The EXPANSE - S03 E01 - Fight or Flight (1080p - AMZN Web-DL)")
+            .unwrap();
+        assert_eq!(m.excess(), &["Web"]);
+
+        let m = Metadata::from("narcos.s01e10.1080p.bluray.x264-rovers").unwrap();
+        assert!(m.excess().is_empty());
+    }
+}
+
+#[cfg(test)]
+mod hdr {
+    use crate::metadata::Metadata;
+    use crate::video::HdrFormat;
+
+    #[test]
+    fn hdr() {
+        let m = Metadata::from(
+            "To.All.The.Boys.Always.And.Forever.2021.1080p.NF.WEB-DL.x265.10bit.HDR.DDP5.1.Atmos-NWD",
+        )
+        .unwrap();
+        assert_eq!(m.hdr(), Some("HDR"));
+        assert_eq!(m.hdr_formats(), vec![HdrFormat::Hdr]);
+        assert!(m.is_hdr());
+        assert_eq!(m.title(), "To All The Boys Always And Forever");
+    }
+
+    #[test]
+    fn combined() {
+        let m = Metadata::from("Dune.2021.2160p.WEB-DL.DV.HDR10.DDP5.1.H.265-GRP").unwrap();
+        assert_eq!(m.hdr(), Some("DV.HDR10"));
+        assert_eq!(
+            m.hdr_formats(),
+            vec![HdrFormat::DolbyVision, HdrFormat::Hdr10]
+        );
+        assert_eq!(m.title(), "Dune");
+    }
+
+    #[test]
+    fn variants() {
+        let formats = |name| Metadata::from(name).unwrap().hdr_formats();
+        assert_eq!(
+            formats("Movie.2020.2160p.UHD.BluRay.HDR10+.x265-GRP"),
+            vec![HdrFormat::Hdr10Plus]
+        );
+        assert_eq!(
+            formats("Movie 2020 2160p Dolby Vision WEB-DL"),
+            vec![HdrFormat::DolbyVision]
+        );
+        assert_eq!(
+            formats("Movie.2020.2160p.DoVi.WEB-DL"),
+            vec![HdrFormat::DolbyVision]
+        );
+        assert_eq!(
+            formats("Planet.Earth.2020.2160p.HLG.WEB"),
+            vec![HdrFormat::Hlg]
+        );
+        let m = Metadata::from("Movie.2020.1080p.SDR.WEB-DL").unwrap();
+        assert_eq!(m.hdr_formats(), vec![HdrFormat::Sdr]);
+        assert!(!m.is_hdr());
+    }
+
+    #[test]
+    fn hdrip_is_not_hdr() {
+        let m = Metadata::from("Blade.Runner.2049.2017.HDRip").unwrap();
+        assert_eq!(m.hdr(), None);
+        assert!(!m.is_hdr());
+        let m = Metadata::from("Movie.2010.DVDRip.XviD").unwrap();
+        assert_eq!(m.hdr(), None);
+    }
+
+    #[test]
+    fn title_words() {
+        for (name, title) in [
+            ("HDR.Movie.2019.1080p.BluRay.x264-GRP", "HDR Movie"),
+            ("DV.Club.2019.1080p.BluRay.x264-GRP", "DV Club"),
+            ("HLG.2020.1080p", "HLG"),
+        ] {
+            let m = Metadata::from(name).unwrap();
+            assert_eq!(m.title(), title, "{}", name);
+            assert_eq!(m.hdr(), None, "{}", name);
+        }
+        let m = Metadata::from("HDR.Movie.2019.2160p.HDR10.WEB-DL").unwrap();
+        assert_eq!(m.title(), "HDR Movie");
+        assert_eq!(m.hdr(), Some("HDR10"));
+    }
+}
+
+#[cfg(test)]
+mod bit_depth {
+    use crate::metadata::Metadata;
+
+    #[test]
+    fn ten_bit() {
+        let m = Metadata::from("Rome.S01E11.The.Spoils.BluRay.10Bit.1080p.Dts.H265-d3g").unwrap();
+        assert_eq!(m.bit_depth(), Some(10));
+
+        let m = Metadata::from(
+            "To.All.The.Boys.Always.And.Forever.2021.1080p.NF.WEB-DL.x265.10bit.HDR.DDP5.1.Atmos-NWD",
+        )
+        .unwrap();
+        assert_eq!(m.bit_depth(), Some(10));
+
+        let m = Metadata::from("Attack on Titan (Shingeki no Kyojin) Season 2 [1080p x265 10bit BD Dual Audio AAC]/Episode 30 - Historia").unwrap();
+        assert_eq!(m.bit_depth(), Some(10));
+    }
+
+    #[test]
+    fn hi10p() {
+        let m = Metadata::from("[Coalgirls] Clannad After Story 2009 [Hi10P 720p BluRay]").unwrap();
+        assert_eq!(m.bit_depth(), Some(10));
+        assert_eq!(m.title(), "Clannad After Story");
+    }
+
+    #[test]
+    fn does_not_leak_into_title() {
+        let m = Metadata::from("Some.Movie.8bit.BluRay.x264-GRP").unwrap();
+        assert_eq!(m.bit_depth(), Some(8));
+        assert_eq!(m.title(), "Some Movie");
+    }
+
+    #[test]
+    fn title_words() {
+        let m = Metadata::from("8-Bit.Christmas.2021.1080p.WEB-DL").unwrap();
+        assert_eq!(m.title(), "8-Bit Christmas");
+        assert_eq!(m.bit_depth(), None);
+        let m = Metadata::from("The.8bit.Guy.2019.1080p.10bit.WEB").unwrap();
+        assert_eq!(m.title(), "The 8bit Guy");
+        assert_eq!(m.bit_depth(), Some(10)); // Without a year, the resolution ends the title.
+        let m = Metadata::from("8-Bit.Christmas.1080p.10bit.WEB-DL").unwrap();
+        assert_eq!(m.title(), "8-Bit Christmas");
+        assert_eq!(m.bit_depth(), Some(10));
+        let m = Metadata::from("10bit Stories").unwrap();
+        assert_eq!(m.title(), "10bit Stories");
+        assert_eq!(m.bit_depth(), None);
+    }
+
+    #[test]
+    fn no_bit_depth() {
+        let m = Metadata::from("narcos.s01e10.1080p.bluray.x264-rovers").unwrap();
+        assert_eq!(m.bit_depth(), None);
+    }
+}
+
+#[cfg(test)]
+mod streaming_service {
+    use crate::metadata::Metadata;
+    use crate::service::StreamingService;
+
+    #[test]
+    fn amazon() {
+        let m =
+            Metadata::from("Euphoria.US.S01E03.Made.You.Look.1080p.AMZN.WEB-DL.DDP5.1.H.264-KiNGS")
+                .unwrap();
+        assert_eq!(m.streaming_service(), Some(StreamingService::Amazon));
+        let m = Metadata::from("The EXPANSE - S03 E01 - Fight or Flight (1080p - AMZN Web-DL)")
+            .unwrap();
+        assert_eq!(m.streaming_service(), Some(StreamingService::Amazon));
+    }
+
+    #[test]
+    fn netflix() {
+        let m = Metadata::from(
+            "To.All.The.Boys.Always.And.Forever.2021.1080p.NF.WEB-DL.x265.10bit.HDR.DDP5.1.Atmos-NWD",
+        )
+        .unwrap();
+        assert_eq!(m.streaming_service(), Some(StreamingService::Netflix));
+        assert_eq!(m.title(), "To All The Boys Always And Forever");
+    }
+
+    #[test]
+    fn others() {
+        let service = |name| Metadata::from(name).unwrap().streaming_service();
+        assert_eq!(
+            service("The.Mandalorian.S02E01.2160p.DSNP.WEB-DL.DDP5.1.Atmos.HDR.HEVC-GRP"),
+            Some(StreamingService::DisneyPlus)
+        );
+        assert_eq!(
+            service("Ted.Lasso.S01E01.1080p.ATVP.WEB-DL.DDP5.1.H.264-GRP"),
+            Some(StreamingService::AppleTvPlus)
+        );
+        assert_eq!(
+            service("Movie.2019.1080p.iT.WEB-DL.DD5.1.H264-GRP"),
+            Some(StreamingService::ITunes)
+        );
+        assert_eq!(
+            service("Letterkenny.S09E01.1080p.CRAV.WEB-DL.AAC2.0.H.264-GRP"),
+            Some(StreamingService::Crave)
+        );
+    }
+
+    #[test]
+    fn ignores_words() {
+        for (name, title) in [
+            ("It.Follows.2014.1080p.BluRay.x264-GRP", "It Follows"),
+            ("STAN.AND.OLLIE.2018.720p.BluRay.x264-GRP", "STAN AND OLLIE"),
+            ("STAN.LEE.2023.1080p.WEB.h264-GRP", "STAN LEE"),
+            ("NF.Docs.2019.1080p.WEB.x264-GRP", "NF Docs"),
+        ] {
+            let m = Metadata::from(name).unwrap();
+            assert_eq!(m.streaming_service(), None, "{}", name);
+            assert_eq!(m.title(), title, "{}", name);
+        }
+        assert_eq!(StreamingService::Hulu.to_string(), "HULU");
+    }
+}
+
+#[cfg(test)]
+mod audio_format {
+    use crate::audio::{AudioCodec, Channels};
+    use crate::metadata::Metadata;
+
+    fn parts(name: &str) -> (String, Option<AudioCodec>, Option<Channels>, bool) {
+        let m = Metadata::from(name).unwrap();
+        let format = m.audio_format().unwrap();
+        (
+            m.audio().unwrap().to_string(),
+            format.codec(),
+            format.channels(),
+            format.atmos(),
+        )
+    }
+
+    #[test]
+    fn dolby_digital_plus_atmos() {
+        assert_eq!(
+            parts(
+                "To.All.The.Boys.Always.And.Forever.2021.1080p.NF.WEB-DL.x265.10bit.HDR.DDP5.1.Atmos-NWD"
+            ),
+            (
+                "DDP5.1.Atmos".to_string(),
+                Some(AudioCodec::DolbyDigitalPlus),
+                Some(Channels::new(5, 1)),
+                true
+            )
+        );
+    }
+
+    #[test]
+    fn lossless() {
+        assert_eq!(
+            parts("Dune.2021.2160p.UHD.BluRay.TrueHD.7.1.Atmos.x265-GRP"),
+            (
+                "TrueHD.7.1.Atmos".to_string(),
+                Some(AudioCodec::TrueHd),
+                Some(Channels::new(7, 1)),
+                true
+            )
+        );
+        assert_eq!(
+            parts("Heat.1995.1080p.BluRay.DTS-HD.MA.5.1.x264-GRP"),
+            (
+                "DTS-HD.MA.5.1".to_string(),
+                Some(AudioCodec::DtsHd),
+                Some(Channels::new(5, 1)),
+                false
+            )
+        );
+        assert_eq!(
+            parts("Akira.1988.1080p.BluRay.FLAC.2.0.x264-GRP"),
+            (
+                "FLAC.2.0".to_string(),
+                Some(AudioCodec::Flac),
+                Some(Channels::new(2, 0)),
+                false
+            )
+        );
+    }
+
+    #[test]
+    fn lossy() {
+        assert_eq!(
+            parts("Show.S01E01.1080p.WEB.EAC3.H264-GRP"),
+            (
+                "EAC3".to_string(),
+                Some(AudioCodec::DolbyDigitalPlus),
+                None,
+                false
+            )
+        );
+        assert_eq!(
+            parts("[Judas] Show - S01E01 (1080p HEVC x265 10bit Opus)"),
+            ("Opus".to_string(), Some(AudioCodec::Opus), None, false)
+        );
+        assert_eq!(
+            parts("Hercules.2014.EXTENDED.1080p.WEB-DL.DD5.1.H264-RARBG"),
+            (
+                "DD5.1".to_string(),
+                Some(AudioCodec::DolbyDigital),
+                Some(Channels::new(5, 1)),
+                false
+            )
+        );
+        assert_eq!(
+            parts("Letterkenny.S09E01.1080p.CRAV.WEB-DL.AAC2.0.H.264-GRP"),
+            (
+                "AAC2.0".to_string(),
+                Some(AudioCodec::Aac),
+                Some(Channels::new(2, 0)),
+                false
+            )
+        );
+    }
+
+    #[test]
+    fn not_a_format() {
+        let m = Metadata::from("Brave.2012.R5.DVDRip.XViD.LiNE-UNiQUE").unwrap();
+        assert_eq!(m.audio(), Some("LiNE"));
+        assert_eq!(m.audio_format(), None);
+        let m = Metadata::from("The.Thin.Red.Line.1998.1080p.BluRay.x264-GRP").unwrap();
+        assert_eq!(m.audio(), None);
+        assert_eq!(m.title(), "The Thin Red Line");
+    }
+
+    #[test]
+    fn title_words() {
+        let m = Metadata::from("Mr. Holland's Opus (1995)").unwrap();
+        assert_eq!(m.title(), "Mr. Holland's Opus");
+        assert_eq!(m.audio(), None);
+        let m = Metadata::from("Mr. Holland's Opus 1080p BluRay x264-GRP").unwrap();
+        assert_eq!(m.title(), "Mr. Holland's Opus");
+        assert_eq!(m.audio(), None);
+        let m = Metadata::from("Magnum.Opus.2019").unwrap();
+        assert_eq!(m.title(), "Magnum Opus");
+        let m = Metadata::from("Atmos.2019.1080p.WEB").unwrap();
+        assert_eq!(m.title(), "Atmos");
+        assert_eq!(m.audio(), None);
+        let m = Metadata::from("Atmos.2019.1080p.WEB.DDP5.1.Atmos-GRP").unwrap();
+        assert_eq!(m.title(), "Atmos");
+        assert_eq!(m.audio(), Some("DDP5.1.Atmos"));
+    }
+
+    #[test]
+    fn channels() {
+        assert_eq!(Channels::new(7, 1).to_string(), "7.1");
+        assert_eq!(Channels::new(5, 1).total(), 6);
+        assert!(Channels::new(7, 1) > Channels::new(5, 1));
+    }
+}
+
+#[cfg(test)]
+mod languages {
+    use crate::metadata::Metadata;
+
+    fn languages(name: &str) -> (Vec<String>, Vec<String>) {
+        let m = Metadata::from(name).unwrap();
+        (
+            m.audio_languages().to_vec(),
+            m.subtitle_languages().to_vec(),
+        )
+    }
+
+    fn codes(codes: &[&str]) -> Vec<String> {
+        codes.iter().map(|c| c.to_string()).collect()
+    }
+
+    #[test]
+    fn audio() {
+        assert_eq!(
+            languages("Movie.2010.rus.eng.720p.BluRay.x264-GRP"),
+            (codes(&["rus", "eng"]), codes(&[]))
+        );
+        assert_eq!(
+            languages("Il.Film.2018.ITA.ENG.1080p.BluRay.x264-GRP"),
+            (codes(&["ita", "eng"]), codes(&[]))
+        );
+        assert_eq!(
+            languages("Der.Film.2018.German.DL.1080p.BluRay.x264-GRP"),
+            (codes(&["deu"]), codes(&[]))
+        );
+        assert_eq!(
+            languages("Le.Film.2019.MULTi.1080p.BluRay.x264-GRP"),
+            (codes(&["mul"]), codes(&[]))
+        );
+        assert_eq!(
+            languages("Movie.2019.ENG.1080p.WEB.x264-GRP"),
+            (codes(&["eng"]), codes(&[]))
+        );
+    }
+
+    #[test]
+    fn subtitles() {
+        assert_eq!(
+            languages("Le.Film.2019.VOSTFR.1080p.WEB.x264-GRP"),
+            (codes(&[]), codes(&["fra"]))
+        );
+        assert_eq!(
+            languages("Movie (2019) Hindi 720p WEB-DL ESub x264"),
+            (codes(&[]), codes(&["eng"]))
+        );
+        assert_eq!(
+            languages("Movie.2019.1080p.BluRay.x264.Multi-Subs-GRP"),
+            (codes(&[]), codes(&["mul"]))
+        );
+    }
+
+    #[test]
+    fn subbed_and_dubbed() {
+        assert_eq!(
+            languages("Show S01E01 Subbed 720p"),
+            (codes(&[]), codes(&["und"]))
+        );
+        assert_eq!(
+            languages("Show S01E01 Dubbed 720p"),
+            (codes(&["und"]), codes(&[]))
+        );
+        assert_eq!(
+            languages("Show S01E01 English Dubbed 720p"),
+            (codes(&["eng"]), codes(&[]))
+        );
+    }
+
+    #[test]
+    fn title_words_are_not_languages() {
+        let m = Metadata::from("The.English.Patient.1996.1080p.BluRay.x264-GRP").unwrap();
+        assert_eq!(m.title(), "The English Patient");
+        assert!(m.audio_languages().is_empty());
+        let m = Metadata::from("The Italian Job (2003) 1080p BrRip x264 - YIFY").unwrap();
+        assert_eq!(m.title(), "The Italian Job");
+        assert!(m.audio_languages().is_empty());
+    }
+
+    #[test]
+    fn title_words_before_episode() {
+        let m = Metadata::from("THE.SPANISH.PRINCESS.S01E01.720p.HDTV").unwrap();
+        assert_eq!(m.title(), "THE SPANISH PRINCESS");
+        assert_eq!(m.language(), None);
+        let m = Metadata::from("THE.SPANISH.PRINCESS.S01E01.FRENCH.720p.HDTV").unwrap();
+        assert_eq!(m.title(), "THE SPANISH PRINCESS");
+        assert_eq!(m.audio_languages(), &["fra"]);
+    }
+
+    #[test]
+    fn repeated_french_tags() {
+        let m = Metadata::from("Le.Film.2019.MULTi.VFF.1080p.BluRay.x264-GRP").unwrap();
+        assert_eq!(m.language(), Some("MULTi.VFF"));
+        assert_eq!(m.audio_languages(), &["mul", "fra"]);
+        assert!(m.excess().is_empty());
+        let m = Metadata::from("Le.Film.2019.MULTi.TRUEFRENCH.1080p.BluRay.x264-GRP").unwrap();
+        assert_eq!(m.language(), Some("MULTi.TRUEFRENCH"));
+        assert!(m.excess().is_empty());
+    }
+}
+
+#[cfg(test)]
+mod editions {
+    use crate::metadata::Metadata;
+    use crate::Edition;
+
+    fn editions(name: &str) -> (String, Vec<Edition>) {
+        let m = Metadata::from(name).unwrap();
+        (m.title().to_string(), m.editions())
+    }
+
+    #[test]
+    fn single() {
+        let cases = [
+            (
+                "Kingdom.of.Heaven.2005.Directors.Cut.1080p.BluRay.x264-GRP",
+                "Kingdom of Heaven",
+                Edition::DirectorsCut,
+            ),
+            (
+                "Aliens (1986) Director's Cut 1080p BluRay x264",
+                "Aliens",
+                Edition::DirectorsCut,
+            ),
+            (
+                "Amadeus.1984.Theatrical.Cut.720p.BluRay.x264-GRP",
+                "Amadeus",
+                Edition::Theatrical,
+            ),
+            (
+                "Jaws.1975.REMASTERED.1080p.BluRay.x264-GRP",
+                "Jaws",
+                Edition::Remastered,
+            ),
+            (
+                "Seven.Samurai.1954.Criterion.Collection.1080p.BluRay.x264-GRP",
+                "Seven Samurai",
+                Edition::Criterion,
+            ),
+            (
+                "Dune.2021.IMAX.2160p.WEB-DL.DDP5.1.Atmos.x265-GRP",
+                "Dune",
+                Edition::Imax,
+            ),
+            (
+                "Close Encounters of the Third Kind 1977 Special Edition 720p BluRay",
+                "Close Encounters of the Third Kind",
+                Edition::SpecialEdition,
+            ),
+            (
+                "Blade.Runner.1982.The.Final.Cut.1080p.BluRay.x264-GRP",
+                "Blade Runner",
+                Edition::FinalCut,
+            ),
+            (
+                "Watchmen.2009.Ultimate.Cut.1080p.BluRay.x264-GRP",
+                "Watchmen",
+                Edition::UltimateEdition,
+            ),
+            (
+                "Back.to.the.Future.1985.30th.Anniversary.Edition.1080p.BluRay.x264-GRP",
+                "Back to the Future",
+                Edition::AnniversaryEdition,
+            ),
+            (
+                "Ghostbusters.1984.Open.Matte.1080p.WEB-DL.x264-GRP",
+                "Ghostbusters",
+                Edition::OpenMatte,
+            ),
+        ];
+        for (name, title, edition) in cases {
+            assert_eq!(
+                editions(name),
+                (title.to_string(), vec![edition]),
+                "{}",
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn several() {
+        let m =
+            Metadata::from("Brazil.1985.Criterion.Remastered.Directors.Cut.1080p.BluRay.x264-GRP")
+                .unwrap();
+        assert_eq!(m.edition(), Some("Criterion.Remastered.Directors.Cut"));
+        assert_eq!(
+            m.editions(),
+            vec![
+                Edition::Criterion,
+                Edition::Remastered,
+                Edition::DirectorsCut
+            ]
+        );
+    }
+
+    #[test]
+    fn alongside_extended() {
+        let m =
+            Metadata::from("Aliens.1986.EXTENDED.Special.Edition.1080p.BluRay.x264-GRP").unwrap();
+        assert_eq!(m.title(), "Aliens");
+        assert!(m.extended());
+        assert_eq!(m.editions(), vec![Edition::SpecialEdition]);
+    }
+
+    #[test]
+    fn title_words() {
+        for (name, title) in [
+            ("The.Final.Cut.2004.720p.BluRay.x264-GRP", "The Final Cut"),
+            ("Special.Edition.2019.1080p", "Special Edition"),
+            ("IMAX.Under.the.Sea.2009", "IMAX Under the Sea"),
+            (
+                "Remastered.Devil.at.the.Crossroads.2019",
+                "Remastered Devil at the Crossroads",
+            ),
+            ("The.Criterion.Affair.2019", "The Criterion Affair"),
+            ("The.Final.Cut.720p.BluRay.x264-GRP", "The Final Cut"),
+        ] {
+            assert_eq!(editions(name), (title.to_string(), vec![]), "{}", name);
+        }
+        assert_eq!(
+            editions("The.Final.Cut.2004.Directors.Cut.720p.BluRay.x264-GRP"),
+            ("The Final Cut".to_string(), vec![Edition::DirectorsCut])
+        );
+    }
+
+    #[test]
+    fn none() {
+        let m = Metadata::from("narcos.s01e10.1080p.bluray.x264-rovers").unwrap();
+        assert_eq!(m.edition(), None);
+        assert!(m.editions().is_empty());
+    }
+}
+
+#[cfg(test)]
+mod air_date {
+    use crate::metadata::Metadata;
+    use crate::{AirDate, Field};
+
+    fn date(year: i32, month: u8, day: u8) -> Option<AirDate> {
+        AirDate::new(year, month, day)
+    }
+
+    #[test]
+    fn year_month_day() {
+        let name = "The.Daily.Show.2023.10.05.Guest.Name.720p.WEB.h264-GROUP";
+        let m = Metadata::from(name).unwrap();
+        assert_eq!(m.title(), "The Daily Show");
+        assert_eq!(m.air_date(), date(2023, 10, 5));
+        assert_eq!(m.year(), None);
+        assert!(m.is_show());
+        assert!(!m.is_special());
+        let span = m.span(Field::AirDate).unwrap();
+        assert_eq!(&name[span.range()], "2023.10.05");
+
+        let m = Metadata::from("Jeopardy 2024-01-31 720p HDTV x264-GRP").unwrap();
+        assert_eq!(m.title(), "Jeopardy");
+        assert_eq!(m.air_date(), date(2024, 1, 31));
+        assert_eq!(m.group(), Some("GRP"));
+
+        let m = Metadata::from("Show.2023-10-05.720p").unwrap();
+        assert_eq!(m.title(), "Show");
+        assert_eq!(m.air_date(), date(2023, 10, 5));
+        assert_eq!(m.group(), None);
+    }
+
+    #[test]
+    fn day_month_year() {
+        let m = Metadata::from("EastEnders.25.12.2022.1080p.HDTV.x264-GRP").unwrap();
+        assert_eq!(m.title(), "EastEnders");
+        assert_eq!(m.air_date(), date(2022, 12, 25));
+        assert_eq!(m.year(), None);
+    }
+
+    #[test]
+    fn month_day_short_year() {
+        let m = Metadata::from("Conan.10.05.09.Guest.Name.HDTV.XviD-GRP").unwrap();
+        assert_eq!(m.title(), "Conan");
+        assert_eq!(m.air_date(), date(2009, 10, 5));
+    }
+
+    #[test]
+    fn show_year_is_kept() {
+        let m = Metadata::from("The.Tonight.Show.(1962).1985.03.14.HDTV.x264-GRP").unwrap();
+        assert_eq!(m.title(), "The Tonight Show");
+        assert_eq!(m.year(), Some(1962));
+        assert_eq!(m.air_date(), date(1985, 3, 14));
+    }
+
+    #[test]
+    fn invalid_dates() {
+        assert_eq!(AirDate::new(2023, 2, 29), None);
+        assert_eq!(AirDate::new(2024, 2, 29).unwrap().to_string(), "2024-02-29");
+        assert_eq!(AirDate::new(2023, 13, 1), None);
+        let m = Metadata::from("Movie.2019.1080p.BluRay.x264-GRP").unwrap();
+        assert_eq!(m.air_date(), None);
+        assert_eq!(m.year(), Some(2019));
+        assert!(!m.is_show());
+    }
+}
+
+#[cfg(test)]
+mod absolute_episode {
+    use crate::metadata::Metadata;
+
+    #[test]
+    fn single() {
+        let m = Metadata::from("[Group] Show - 1052 [1080p]").unwrap();
+        assert_eq!(m.title(), "Show");
+        assert_eq!(m.absolute_episode(), Some(1052));
+        assert_eq!(m.year(), None);
+        assert_eq!(m.season(), None);
+        assert_eq!(m.episode(), None);
+        assert_eq!(m.resolution(), Some("1080p"));
+        assert!(!m.batch());
+        assert!(m.is_show());
+    }
+
+    #[test]
+    fn version() {
+        let m = Metadata::from("[Erai-raws] Show - 05v2 [720p].mkv").unwrap();
+        assert_eq!(m.title(), "Show");
+        assert_eq!(m.absolute_episode(), Some(5));
+    }
+
+    #[test]
+    fn ranges() {
+        for name in [
+            "[Group] Show - 01-12 (Batch)",
+            "[Group] Show - 01~12 [1080p]",
+        ] {
+            let m = Metadata::from(name).unwrap();
+            assert_eq!(m.title(), "Show", "{}", name);
+            assert_eq!(m.absolute_episodes(), &(1..=12).collect::<Vec<_>>());
+            assert!(m.batch(), "{}", name);
+        }
+    }
+
+    #[test]
+    fn batch_without_range() {
+        let m = Metadata::from("[Group] Show Batch [1080p]").unwrap();
+        assert_eq!(m.title(), "Show");
+        assert_eq!(m.absolute_episode(), None);
+        assert!(m.batch());
+    }
+
+    #[test]
+    fn batch_title_word() {
+        let m = Metadata::from("Batch.Man.2019.1080p.WEB.x264-GRP").unwrap();
+        assert_eq!(m.title(), "Batch Man");
+        assert!(!m.batch());
+        let m = Metadata::from("Show.S01.1080p.Batch.WEB.x264-GRP").unwrap();
+        assert_eq!(m.title(), "Show");
+        assert!(m.batch());
+    }
+
+    #[test]
+    fn season_and_episode_win() {
+        let m =
+            Metadata::from("[SubsPlease] Fumetsu no Anata e S2 - 01 (1080p) [1D65E30D]").unwrap();
+        assert_eq!(m.season(), Some(2));
+        assert_eq!(m.episode(), Some(1));
+        assert_eq!(m.absolute_episode(), None);
+    }
+}
+
+#[cfg(test)]
+mod crc32 {
+    use crate::metadata::Metadata;
+    use std::fs;
+
+    #[test]
+    fn parsed() {
+        let m = Metadata::from("[SubsPlease] Dr. Stone S2 - 07 (1080p) [33538C7C]").unwrap();
+        assert_eq!(m.crc32(), Some(0x3353_8C7C));
+        assert_eq!(m.title(), "Dr. Stone");
+        assert!(m.excess().is_empty());
+        let m =
+            Metadata::from("[SubsPlease] Fumetsu no Anata e S2 - 01 (1080p) [1D65E30D]").unwrap();
+        assert_eq!(m.crc32(), Some(0x1D65_E30D));
+        let m = Metadata::from("[Group] Show - 05 [1080p][abcdef01].mkv").unwrap();
+        assert_eq!(m.crc32(), Some(0xABCD_EF01));
+        assert_eq!(m.extension(), Some("mkv"));
+    }
+
+    #[test]
+    fn not_a_checksum() {
+        let m = Metadata::from(
+            "[TorrentCounter.to].Pacific.Rim.2.Uprising.2018.1080p.HC.HDRip.x264.[2GB]",
+        )
+        .unwrap();
+        assert_eq!(m.crc32(), None);
+        let m = Metadata::from("narcos.s01e10.1080p.bluray.x264-rovers").unwrap();
+        assert_eq!(m.verify_crc32("does-not-matter").unwrap(), None);
+    }
+
+    #[test]
+    fn verify() {
+        let dir = std::env::temp_dir().join(format!("tnp-crc32-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("episode.mkv");
+        fs::write(&path, b"123456789").unwrap();
+
+        let good = Metadata::from("[Group] Show - 01 [1080p] [CBF43926].mkv").unwrap();
+        assert_eq!(good.verify_crc32(&path).unwrap(), Some(true));
+        let bad = Metadata::from("[Group] Show - 01 [1080p] [CBF43927].mkv").unwrap();
+        assert_eq!(bad.verify_crc32(&path).unwrap(), Some(false));
+        assert!(good.verify_crc32(dir.join("missing.mkv")).is_err());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
+
+#[cfg(test)]
+mod size {
+    use crate::metadata::Metadata;
+
+    fn size(name: &str) -> Option<u64> {
+        Metadata::from(name).unwrap().size()
+    }
+
+    #[test]
+    fn units() {
+        assert_eq!(
+            size("[TorrentCounter.to].Pacific.Rim.2.Uprising.2018.1080p.HC.HDRip.x264.[2GB]"),
+            Some(2_000_000_000)
+        );
+        assert_eq!(
+            size("[ www.UsaBit.com ] - My Cousin Vinny (1992) BluRay 720p 750MB Ganool"),
+            Some(750_000_000)
+        );
+        assert_eq!(
+            size("Lucy 2014 Dual-Audio WEBRip 1400Mb"),
+            Some(1_400_000_000)
+        );
+        assert_eq!(size("Movie (2001) 512KB"), Some(512_000));
+        assert_eq!(
+            size("Movie.2019.1080p.WEB.x264.4GiB"),
+            Some(4 * 1024 * 1024 * 1024)
+        );
+    }
+
+    #[test]
+    fn decimals_and_spaces() {
+        assert_eq!(size("Movie (2019) 720p WEB [1.4 GB]"), Some(1_400_000_000));
+        assert_eq!(size("Film (2019) 720p WEB [1,4 GB]"), Some(1_400_000_000));
+        assert_eq!(size("Movie (2019) 720p WEB 700 MB"), Some(700_000_000));
+    }
+
+    #[test]
+    fn not_garbage() {
+        let m = Metadata::from("Lucy 2014 Dual-Audio WEBRip 1400Mb").unwrap();
+        assert_eq!(m.title(), "Lucy");
+        assert!(!m.excess().iter().any(|e| e.contains("1400Mb")));
+    }
+
+    #[test]
+    fn none() {
+        assert_eq!(size("narcos.s01e10.1080p.bluray.x264-rovers"), None);
+        assert_eq!(
+            size("Dune.2021.2160p.UHD.BluRay.TrueHD.7.1.Atmos.x265-GRP"),
+            None
+        );
+    }
+}
+
+#[cfg(test)]
+mod season_packs {
+    use crate::metadata::Metadata;
+
+    #[test]
+    fn ranges() {
+        let m = Metadata::from("Show.S01-S05.Complete.1080p.BluRay.x264-GRP").unwrap();
+        assert_eq!(m.title(), "Show");
+        assert_eq!(m.seasons(), &vec![1, 2, 3, 4, 5]);
+        assert_eq!(m.season(), Some(1));
+        assert_eq!(m.episode(), None);
+        assert_eq!(m.group(), Some("GRP"));
+        assert!(m.complete());
+
+        for name in ["Show.S01-S05.Complete", "Show.S01-S05.Complete.720p.BluRay"] {
+            let m = Metadata::from(name).unwrap();
+            assert_eq!(m.title(), "Show", "{}", name);
+            assert_eq!(m.seasons(), &vec![1, 2, 3, 4, 5], "{}", name);
+            assert_eq!(m.group(), None, "{}", name);
+            assert!(m.complete(), "{}", name);
+        }
+
+        let m = Metadata::from("Show Seasons 1-3 720p HDTV").unwrap();
+        assert_eq!(m.title(), "Show");
+        assert_eq!(m.seasons(), &vec![1, 2, 3]);
+        assert_eq!(m.episode(), None);
+        assert!(!m.complete());
+
+        let m = Metadata::from("Show Seasons 1-3 Complete 1080p").unwrap();
+        assert_eq!(m.title(), "Show");
+        assert_eq!(m.seasons(), &vec![1, 2, 3]);
+        assert_eq!(m.episode(), None);
+        assert!(m.episodes().is_empty());
+        assert!(m.complete());
+
+        let m = Metadata::from("Friends.Season.1-10.Complete.720p.BluRay.x264-GRP").unwrap();
+        assert_eq!(m.title(), "Friends");
+        assert_eq!(m.seasons(), &(1..=10).collect::<Vec<_>>());
+        assert_eq!(m.episode(), None);
+        assert_eq!(m.group(), Some("GRP"));
+        assert!(m.complete());
+    }
+
+    #[test]
+    fn list() {
+        let m = Metadata::from("Show.S01.S02.S03.1080p.WEB-DL-GRP").unwrap();
+        assert_eq!(m.title(), "Show");
+        assert_eq!(m.seasons(), &vec![1, 2, 3]);
+        assert!(m.excess().is_empty());
+    }
+
+    #[test]
+    fn complete_series() {
+        let m = Metadata::from("Show Complete Series 720p HDTV").unwrap();
+        assert_eq!(m.title(), "Show");
+        assert!(m.complete());
+        assert!(m.seasons().is_empty());
+
+        let m = Metadata::from("Show.S01.COMPLETE.720p.WEB.x264-GRP").unwrap();
+        assert_eq!(m.seasons(), &vec![1]);
+        assert!(m.complete());
+
+        let m = Metadata::from("Show.COMPLETE.720p.WEB.x264-GRP").unwrap();
+        assert_eq!(m.title(), "Show");
+        assert!(m.complete());
+    }
+
+    #[test]
+    fn single_season() {
+        let m = Metadata::from("narcos.s01e10.1080p.bluray.x264-rovers").unwrap();
+        assert_eq!(m.seasons(), &vec![1]);
+        assert!(!m.complete());
+
+        let m = Metadata::from("[SubsPlease] Dr. Stone S2 - 07 (1080p) [33538C7C]").unwrap();
+        assert_eq!(m.seasons(), &vec![2]);
+        assert_eq!(m.episode(), Some(7));
+
+        let m = Metadata::from("A.Complete.Unknown.2024.1080p.WEB.x264-GRP").unwrap();
+        assert_eq!(m.title(), "A Complete Unknown");
+        assert!(!m.complete());
+        let m = Metadata::from("COMPLETE.UNKNOWN.2024.1080p.WEB.x264-GRP").unwrap();
+        assert_eq!(m.title(), "COMPLETE UNKNOWN");
+        assert!(!m.complete());
+    }
+}
+
+#[cfg(test)]
+mod episode_title {
+    use crate::metadata::Metadata;
+    use crate::Field;
+
+    #[test]
+    fn dotted() {
+        let name = "Euphoria.US.S01E03.Made.You.Look.1080p.AMZN.WEB-DL.DDP5.1.H.264-KiNGS";
+        let m = Metadata::from(name).unwrap();
+        assert_eq!(m.title(), "Euphoria");
+        assert_eq!(m.episode_title(), Some("Made You Look"));
+        let span = m.span(Field::EpisodeTitle).unwrap();
+        assert_eq!(&name[span.range()], "Made.You.Look");
+    }
+
+    #[test]
+    fn spaced() {
+        let m = Metadata::from("The EXPANSE - S03 E01 - Fight or Flight (1080p - AMZN Web-DL)")
+            .unwrap();
+        assert_eq!(m.episode_title(), Some("Fight or Flight"));
+    }
+
+    #[test]
+    fn none() {
+        for name in [
+            "narcos.s01e10.1080p.bluray.x264-rovers",
+            "the.expanse.s01e09e11.1080p.bluray.x264-rovers",
+            "The Simpsons S26E05 HDTV x264 PROPER-LOL [eztv]",
+            "Hercules.2014.EXTENDED.1080p.WEB-DL.DD5.1.H264-RARBG",
+        ] {
+            let m = Metadata::from(name).unwrap();
+            assert_eq!(m.episode_title(), None, "{}", name);
+        }
+    }
+
+    #[test]
+    fn packs() {
+        for name in [
+            "Show Seasons 1-3 Complete 1080p",
+            "Show.S01E01-E12.NORDiC.720p.WEB.H264-GRP",
+            "Show.S01.S02.NORDiC.720p.WEB.H264-GRP",
+        ] {
+            let m = Metadata::from(name).unwrap();
+            assert_eq!(m.episode_title(), None, "{}", name);
+            assert_eq!(m.span(Field::EpisodeTitle), None, "{}", name);
+        }
+    }
+}
+
+#[cfg(test)]
+mod numbered_files {
+    use crate::metadata::Metadata;
+
+    #[test]
+    fn cd() {
+        let m = Metadata::from("Movie.1999.CD1.avi").unwrap();
+        assert_eq!(m.title(), "Movie");
+        assert_eq!(m.disc(), Some(1));
+        assert_eq!(m.extension(), Some("avi"));
+        let m = Metadata::from("Movie.1999.CD2.avi").unwrap();
+        assert_eq!(m.disc(), Some(2));
+    }
+
+    #[test]
+    fn disc() {
+        let m = Metadata::from("Movie (1999) Disc 3 DVDRip").unwrap();
+        assert_eq!(m.title(), "Movie");
+        assert_eq!(m.disc(), Some(3));
+    }
+
+    #[test]
+    fn part() {
+        let m = Metadata::from("Movie.1999.Part.1.720p.BluRay.x264-GRP").unwrap();
+        assert_eq!(m.title(), "Movie");
+        assert_eq!(m.part(), Some(1));
+        let m = Metadata::from("Movie.1999.Pt2.x264-GRP").unwrap();
+        assert_eq!(m.part(), Some(2));
+    }
+
+    #[test]
+    fn not_a_season_or_episode() {
+        let m = Metadata::from("Movie.1999.Pt2.x264-GRP").unwrap();
+        assert_eq!(m.season(), None);
+        assert_eq!(m.episode(), None);
+        assert!(!m.is_show());
+    }
+
+    #[test]
+    fn in_title_before_year() {
+        let m = Metadata::from("Kill.Bill.Vol.2.2004.1080p.BluRay.x264-GRP").unwrap();
+        assert_eq!(m.title(), "Kill Bill Vol 2");
+        assert_eq!(m.volume(), Some(2));
+        let m = Metadata::from(
+            "Harry.Potter.and.the.Deathly.Hallows.Part.2.2011.1080p.BluRay.x264-GRP",
+        )
+        .unwrap();
+        assert_eq!(m.title(), "Harry Potter and the Deathly Hallows Part 2");
+        assert_eq!(m.part(), Some(2));
+        let m = Metadata::from("Kill Bill Vol 2 Extended 2004 1080p BluRay").unwrap();
+        assert_eq!(m.title(), "Kill Bill Vol 2 Extended");
+        assert_eq!(m.volume(), Some(2));
+        assert_eq!(m.season(), None);
+        assert!(m.excess().is_empty());
+    }
+
+    #[test]
+    fn not_read_as_season() {
+        let m = Metadata::from("Some Movie Disc 3 Extras").unwrap();
+        assert_eq!(m.title(), "Some Movie");
+        assert_eq!(m.disc(), Some(3));
+        assert_eq!(m.season(), None);
+        assert_eq!(m.excess(), &["Extras"]);
+    }
+
+    #[test]
+    fn volume() {
+        let m = Metadata::from("Show.Vol.3.720p.WEB.x264-GRP").unwrap();
+        assert_eq!(m.title(), "Show");
+        assert_eq!(m.volume(), Some(3));
+    }
+}
+
+#[cfg(test)]
+mod from_path {
+    use crate::error::ErrorMatch;
+    use crate::metadata::Metadata;
+    use crate::Field;
+    use std::path::Path;
+
+    #[test]
+    fn show_season_episode() {
+        let m = Metadata::from_path("Show Name/Season 02/03 - Title.mkv").unwrap();
+        assert_eq!(m.title(), "Show Name");
+        assert_eq!(m.season(), Some(2));
+        assert_eq!(m.episode(), Some(3));
+        assert_eq!(m.episode_title(), Some("Title"));
+        assert_eq!(m.extension(), Some("mkv"));
+        assert_eq!(m.span(Field::Episode).unwrap().range(), 0..2);
+        assert_eq!(m.span(Field::EpisodeTitle).unwrap().range(), 5..10);
+    }
+
+    #[test]
+    fn bare_episode_number() {
+        let m =
+            Metadata::from_path(Path::new("/media/tv/The Wire (2002)/Season 1/05.mkv")).unwrap();
+        assert_eq!(m.title(), "The Wire");
+        assert_eq!(m.year(), Some(2002));
+        assert_eq!(m.season(), Some(1));
+        assert_eq!(m.episode(), Some(5));
+        assert_eq!(m.extension(), Some("mkv"));
+        assert_eq!(m.span(Field::Episode).unwrap().range(), 0..2);
+        assert_eq!(m.span(Field::Extension).unwrap().range(), 3..6);
+    }
+
+    #[test]
+    fn file_overrides_folder_for_episode() {
+        let m = Metadata::from_path(
+            "Attack on Titan (Shingeki no Kyojin) Season 2 [1080p x265 10bit BD Dual Audio AAC]/Episode 30 - Historia",
+        )
+        .unwrap();
+        assert_eq!(m.title(), "Attack on Titan");
+        assert_eq!(m.season(), Some(2));
+        assert_eq!(m.episode(), Some(30));
+        assert_eq!(m.episode_title(), Some("Historia"));
+        assert_eq!(m.group(), None);
+        assert_eq!(m.resolution(), Some("1080p"));
+        assert_eq!(m.bit_depth(), Some(10));
+
+        let m = Metadata::from_path(
+            "Attack on Titan (2013) Season 2 [1080p x265 10bit BD Dual Audio AAC]/Episode 30 - Historia.mkv",
+        )
+        .unwrap();
+        assert_eq!(m.title(), "Attack on Titan");
+        assert_eq!(m.year(), Some(2013));
+        assert_eq!(m.episode(), Some(30));
+        assert_eq!(m.episode_title(), Some("Historia"));
+        assert_eq!(m.group(), None);
+        assert_eq!(m.extension(), Some("mkv"));
+
+        let m = Metadata::from_path("Show.S01.1080p.WEB-DL/Show.S01E04.mkv").unwrap();
+        assert_eq!(m.title(), "Show");
+        assert_eq!(m.season(), Some(1));
+        assert_eq!(m.episode(), Some(4));
+        assert_eq!(m.resolution(), Some("1080p"));
+
+        // The extension is not part of the group.
+        let m = Metadata::from_path("Show.S01.1080p.WEB-DL/Show.S01E04.720p.WEB-GRP.mkv").unwrap();
+        assert_eq!(m.resolution(), Some("720p"));
+        assert_eq!(m.group(), Some("GRP"));
+        assert_eq!(m.episode_title(), None);
+        assert_eq!(m.extension(), Some("mkv"));
+    }
+
+    #[test]
+    fn folder_fills_pack_fields() {
+        let m = Metadata::from_path(
+            "Show.S01-S03.COMPLETE.1080p.BluRay.x264-GRP [1.4GB]/Show.S01E01.mkv",
+        )
+        .unwrap();
+        assert_eq!(m.title(), "Show");
+        assert_eq!(m.episode(), Some(1));
+        assert!(m.complete());
+        assert_eq!(m.resolution(), Some("1080p"));
+        // The size is the whole pack's, not the file's.
+        assert_eq!(m.size(), None);
+
+        let m = Metadata::from_path("Some Movie 2004 Disc 2/Some.Movie.2004.mkv").unwrap();
+        assert_eq!(m.disc(), Some(2));
+    }
+
+    #[test]
+    fn file_title_wins() {
+        let m = Metadata::from_path("Movies/Heat.1995.1080p.BluRay.x264-GRP.mkv").unwrap();
+        assert_eq!(m.title(), "Heat");
+        assert_eq!(m.year(), Some(1995));
+        // Without a season folder a leading number is not an episode.
+        let m = Metadata::from_path("Movies/300 (2006).mkv").unwrap();
+        assert_eq!(m.title(), "300");
+        assert_eq!(m.episode(), None);
+    }
+
+    #[test]
+    fn flat_name_as_path() {
+        let name = "The Walking Dead S05E03 720p HDTV x264-ASAP[ettv]";
+        assert_eq!(
+            Metadata::from_path(name).unwrap(),
+            Metadata::from(name).unwrap()
+        );
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(Metadata::from_path(""), Err(ErrorMatch::EmptyInput));
+        let err = Metadata::from_path("Season 02/03.mkv").unwrap_err();
+        assert!(matches!(err, ErrorMatch::NoTitle { .. }));
+        let err = Metadata::from_path("/[Rip Time] S01E12 [1080p]").unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("consumed by Website \"[Rip Time] \""));
+    }
+}
+
+#[cfg(all(test, feature = "torrent"))]
+mod torrent {
+    use crate::error::{ErrorMatch, TorrentError};
+    use crate::{Field, Parser, TorrentMetadata};
+    use std::path::Path;
+
+    fn text(s: &str) -> String {
+        format!("{}:{}", s.len(), s)
+    }
+
+    fn file(path: &[&str], length: u64) -> String {
+        let parts: String = path.iter().map(|p| text(p)).collect();
+        format!("d6:lengthi{}e4:pathl{}ee", length, parts)
+    }
+
+    fn multi_file(name: &str, files: &[String]) -> Vec<u8> {
+        format!(
+            "d8:announce{}4:infod5:filesl{}e4:name{}12:piece lengthi262144eee",
+            text("http://tracker.example/announce"),
+            files.concat(),
+            text(name)
+        )
+        .into_bytes()
+    }
+
+    #[test]
+    fn season_pack() {
+        let torrent = multi_file(
+            "Show.S01.1080p.BluRay.x264-GRP",
+            &[
+                file(&["Show.S01E01.1080p.BluRay.x264-GRP.mkv"], 1_000),
+                file(&["Show.S01E02.1080p.BluRay.x264-GRP.mkv"], 2_000),
+                file(&["Sample", "sample.txt"], 10),
+                file(&["Show.S01.1080p.BluRay.x264-GRP.nfo"], 5),
+            ],
+        );
+        let t = TorrentMetadata::from_bytes(&torrent).unwrap();
+        assert_eq!(t.name(), "Show.S01.1080p.BluRay.x264-GRP");
+        assert_eq!(t.metadata().unwrap().title(), "Show");
+        assert_eq!(t.metadata().unwrap().season(), Some(1));
+
+        let episodes: Vec<_> = t.files().iter().map(|f| f.metadata().episode()).collect();
+        assert_eq!(episodes, vec![Some(1), Some(2)]);
+        assert_eq!(
+            t.files()[1].path(),
+            Path::new("Show.S01E02.1080p.BluRay.x264-GRP.mkv")
+        );
+        assert_eq!(t.files()[1].length(), 2_000);
+        assert_eq!(t.files()[1].metadata().title(), "Show");
+    }
+
+    #[test]
+    fn folders_inside_the_torrent() {
+        let torrent = multi_file(
+            "Show Name",
+            &[file(&["Season 02", "03 - Title.mkv"], 1_000)],
+        );
+        let t = TorrentMetadata::from_bytes(&torrent).unwrap();
+        let m = t.files()[0].metadata();
+        assert_eq!(m.title(), "Show Name");
+        assert_eq!(m.season(), Some(2));
+        assert_eq!(m.episode(), Some(3));
+    }
+
+    #[test]
+    fn name_without_title() {
+        let torrent = multi_file(
+            "Season 1",
+            &[file(&["Show.S01E01.720p.HDTV.x264-GRP.mkv"], 1_000)],
+        );
+        let t = TorrentMetadata::from_bytes(&torrent).unwrap();
+        assert!(matches!(t.metadata(), Err(ErrorMatch::NoTitle { .. })));
+        let m = t.files()[0].metadata();
+        assert_eq!(m.title(), "Show");
+        assert_eq!(m.season(), Some(1));
+        assert_eq!(m.episode(), Some(1));
+    }
+
+    #[test]
+    fn parser_options() {
+        let torrent = multi_file(
+            "Show.S01.1080p.WEB-DL",
+            &[file(&["Show.S01E01.1080p.WEB-DL.mkv"], 1_000)],
+        );
+        let parser = Parser::builder().replace_separators(false).build();
+        let t = parser.parse_torrent(&torrent).unwrap();
+        assert_eq!(t.metadata().unwrap().title(), "Show");
+        assert_eq!(t.files()[0].metadata().episode(), Some(1));
+
+        let parser = Parser::builder()
+            .fields(&[Field::Season, Field::Episode])
+            .build();
+        let t = parser.parse_torrent(&torrent).unwrap();
+        assert_eq!(t.metadata().unwrap().resolution(), None);
+        assert_eq!(t.files()[0].metadata().resolution(), None);
+        assert!(matches!(
+            parser.parse_torrent_file("does/not/exist.torrent"),
+            Err(TorrentError::Io(_))
+        ));
+    }
+
+    #[test]
+    fn paths_outside_the_torrent() {
+        let torrent = multi_file(
+            "Show.S01.1080p.WEB-DL",
+            &[
+                file(&["..", "..", "Show.S01E01.mkv"], 1),
+                file(&["/etc", "Show.S01E02.mkv"], 1),
+                file(&["", "Show.S01E03.mkv"], 1),
+                file(&["Extras", "..", "Show.S01E04.mkv"], 1),
+                file(&["Season 1", "Show.S01E05.mkv"], 1),
+            ],
+        );
+        let t = TorrentMetadata::from_bytes(&torrent).unwrap();
+        let paths: Vec<_> = t.files().iter().map(|f| f.path()).collect();
+        assert_eq!(paths, vec![Path::new("Season 1/Show.S01E05.mkv")]);
+
+        let torrent = format!("d4:infod6:lengthi1e4:name{}ee", text("../Movie.2019.mkv"));
+        let t = TorrentMetadata::from_bytes(torrent.as_bytes()).unwrap();
+        assert!(t.files().is_empty());
+    }
+
+    #[test]
+    fn single_file() {
+        let torrent = format!(
+            "d4:infod6:lengthi700e4:name{}ee",
+            text("Movie.2019.1080p.WEB.x264-GRP.mkv")
+        );
+        let t = TorrentMetadata::from_bytes(torrent.as_bytes()).unwrap();
+        assert_eq!(t.metadata().unwrap().title(), "Movie");
+        assert_eq!(t.files().len(), 1);
+        assert_eq!(t.files()[0].length(), 700);
+        assert_eq!(t.files()[0].metadata().year(), Some(2019));
+    }
+
+    #[test]
+    fn utf8_name() {
+        let torrent = format!(
+            "d4:infod6:lengthi1e4:name{}10:name.utf-8{}ee",
+            text("Pok?mon.mkv"),
+            text("Pokémon.mkv")
+        );
+        let t = TorrentMetadata::from_bytes(torrent.as_bytes()).unwrap();
+        assert_eq!(t.name(), "Pokémon.mkv");
+    }
+
+    #[test]
+    fn errors() {
+        assert!(matches!(
+            TorrentMetadata::from_bytes(b"d4:infod4:name"),
+            Err(TorrentError::Bencode(14))
+        ));
+        assert!(matches!(
+            TorrentMetadata::from_bytes(b"d3:fooi1ee"),
+            Err(TorrentError::Missing("info"))
+        ));
+        assert!(matches!(
+            TorrentMetadata::from_bytes(b"d4:infod6:lengthi1eee"),
+            Err(TorrentError::Missing("info.name"))
+        ));
+        let nested = format!("{}{}", "l".repeat(100_000), "e".repeat(100_000));
+        assert!(matches!(
+            TorrentMetadata::from_bytes(nested.as_bytes()),
+            Err(TorrentError::Bencode(_))
+        ));
+        assert!(matches!(
+            TorrentMetadata::from_bytes(b"99999999999999999999:x"),
+            Err(TorrentError::Bencode(_))
+        ));
+        assert!(matches!(
+            TorrentMetadata::from_file("does/not/exist.torrent"),
+            Err(TorrentError::Io(_))
+        ));
+    }
+}
+
+#[cfg(test)]
+mod magnet {
+    use crate::error::{ErrorMatch, MagnetError};
+    use crate::metadata::Metadata;
+
+    const HASH: &str = "c12fe1c06bba254a9dc9f519b335aa7c1367a88a";
+
+    #[test]
+    fn name_and_trackers() {
+        let magnet = Metadata::from_magnet(
+            "magnet:?xt=urn:btih:C12FE1C06BBA254A9DC9F519B335AA7C1367A88A&dn=narcos.s01e10.1080p.bluray.x264-rovers&tr=udp%3A%2F%2Fone.example%3A80&tr.1=http%3A%2F%2Ftwo.example%2Fannounce",
+        )
+        .unwrap();
+        assert_eq!(magnet.info_hash(), HASH);
+        assert_eq!(
+            magnet.name(),
+            Some("narcos.s01e10.1080p.bluray.x264-rovers")
+        );
+        assert_eq!(
+            magnet.trackers(),
+            &["udp://one.example:80", "http://two.example/announce"]
+        );
+        assert_eq!(
+            magnet.metadata(),
+            Some(Ok(&Metadata::from(
+                "narcos.s01e10.1080p.bluray.x264-rovers"
+            )
+            .unwrap()))
+        );
+    }
+
+    #[test]
+    fn percent_and_plus() {
+        let magnet = Metadata::from_magnet(&format!(
+            "magnet:?xt=urn:btih:{}&dn=Pok%C3%A9mon+the+Movie+-+Black+(2011)+%5Bx265+Bluray-1080p%5D+100%",
+            HASH
+        ))
+        .unwrap();
+        assert_eq!(
+            magnet.name(),
+            Some("Pokémon the Movie - Black (2011) [x265 Bluray-1080p] 100%")
+        );
+        assert_eq!(magnet.metadata().unwrap().unwrap().year(), Some(2011));
+    }
+
+    #[test]
+    fn base32_info_hash() {
+        let magnet =
+            Metadata::from_magnet("magnet:?xt=urn:btih:YEX6DQDLXISUVHOJ6UM3GNNKPQJWPKEK").unwrap();
+        assert_eq!(magnet.info_hash(), HASH);
+    }
+
+    #[test]
+    fn missing_name() {
+        let magnet = Metadata::from_magnet(&format!("magnet:?xt=urn:btih:{}", HASH)).unwrap();
+        assert_eq!(magnet.name(), None);
+        assert_eq!(magnet.metadata(), None);
+        assert!(magnet.trackers().is_empty());
+    }
+
+    #[test]
+    fn name_without_title() {
+        let magnet = Metadata::from_magnet(&format!(
+            "magnet:?xt=urn:btih:{}&dn=+&tr=udp%3A%2F%2Fone.example%3A80",
+            HASH
+        ))
+        .unwrap();
+        assert_eq!(magnet.info_hash(), HASH);
+        assert_eq!(magnet.trackers(), &["udp://one.example:80"]);
+        assert_eq!(magnet.metadata(), Some(Err(&ErrorMatch::EmptyInput)));
+
+        let magnet =
+            Metadata::from_magnet(&format!("magnet:?xt=urn:btih:{}&dn=S01E01.720p", HASH)).unwrap();
+        assert_eq!(magnet.info_hash(), HASH);
+        assert!(matches!(
+            magnet.metadata(),
+            Some(Err(ErrorMatch::NoTitle { .. }))
+        ));
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            Metadata::from_magnet("https://example.org/file.torrent"),
+            Err(MagnetError::NotMagnet)
+        );
+        assert_eq!(
+            Metadata::from_magnet("magnet:?dn=Movie.2019.mkv"),
+            Err(MagnetError::MissingInfoHash)
+        );
+        assert_eq!(
+            Metadata::from_magnet("magnet:?xt=urn:btih:1234"),
+            Err(MagnetError::InvalidInfoHash("1234".to_string()))
+        );
+    }
+}
+
+#[cfg(test)]
+mod parser {
+    use crate::error::{ErrorMatch, Warning};
+    use crate::metadata::Metadata;
+    use crate::{Field, Parser};
+
+    const NAMES: &[&str] = &[
+        "The Walking Dead S05E03 720p HDTV x264-ASAP[ettv]",
+        "Euphoria.US.S01E03.Made.You.Look.1080p.AMZN.WEB-DL.DDP5.1.H.264-KiNGS",
+        "[SubsPlease] Dr. Stone S2 - 07 (1080p) [33538C7C]",
+        "Hercules (2014) 1080p BrRip H264 - YIFY",
+    ];
+
+    #[test]
+    fn default_matches_metadata_from() {
+        let parser = Parser::new();
+        for name in NAMES {
+            assert_eq!(parser.parse(name), Metadata::from(name), "{}", name);
+        }
+        assert_eq!(
+            format!("{:?}", Parser::builder().build()),
+            format!("{:?}", Parser::default())
+        );
+    }
+
+    #[test]
+    fn fields() {
+        let parser = Parser::builder()
+            .fields(&[Field::Season, Field::Episode, Field::Resolution])
+            .build();
+        let m = parser
+            .parse("Euphoria.US.S01E03.Made.You.Look.1080p.AMZN.WEB-DL.DDP5.1.H.264-KiNGS")
+            .unwrap();
+        assert_eq!(m.title(), "Euphoria US");
+        assert_eq!(m.country(), None);
+        assert_eq!(m.episode(), Some(3));
+        assert_eq!(m.resolution(), Some("1080p"));
+        assert_eq!(m.episode_title(), None);
+        assert_eq!(m.group(), None);
+        assert_eq!(m.streaming_service(), None);
+    }
+
+    #[test]
+    fn keep_separators() {
+        let parser = Parser::builder().replace_separators(false).build();
+        let m = parser
+            .parse("Euphoria.US.S01E03.Made.You.Look.1080p.AMZN.WEB-DL.DDP5.1.H.264-KiNGS")
+            .unwrap();
+        assert_eq!(m.title(), "Euphoria");
+        assert_eq!(m.episode_title(), Some("Made.You.Look"));
+        let m = parser.parse("Pacific_Rim_2013_720p_BluRay").unwrap();
+        assert_eq!(m.title(), "Pacific_Rim");
+    }
+
+    #[test]
+    fn year_in_title() {
+        let parser = Parser::builder().year_in_title(true).build();
+        let m = parser
+            .parse("Doctor.Who.2005.S01E01.720p.BluRay.x264-GRP")
+            .unwrap();
+        assert_eq!(m.title(), "Doctor Who 2005");
+        assert_eq!(m.year(), Some(2005));
+        assert_eq!(
+            Metadata::from("Doctor.Who.2005.S01E01.720p.BluRay.x264-GRP")
+                .unwrap()
+                .title(),
+            "Doctor Who"
+        );
+    }
+
+    #[test]
+    fn default_season() {
+        let parser = Parser::builder().default_season(1).build();
+        let m = parser.parse("[Group] Show - 05 [1080p]").unwrap();
+        assert_eq!(m.season(), Some(1));
+        assert_eq!(m.seasons(), &vec![1]);
+        assert_eq!(m.absolute_episode(), Some(5));
+        let m = parser
+            .parse("[SubsPlease] Dr. Stone S2 - 07 (1080p) [33538C7C]")
+            .unwrap();
+        assert_eq!(m.season(), Some(2));
+        let m = parser
+            .parse("Hercules (2014) 1080p BrRip H264 - YIFY")
+            .unwrap();
+        assert_eq!(m.season(), None);
+    }
+
+    #[test]
+    fn strictness() {
+        let name = "[Rip Time] S01E12 [1080p]";
+        assert!(matches!(
+            Parser::new().parse(name),
+            Err(ErrorMatch::NoTitle { .. })
+        ));
+        let lax = Parser::builder().strict(false).build();
+        let m = lax.parse(name).unwrap();
+        assert_eq!(m.maybe_title(), None);
+        assert_eq!(m.resolution(), Some("1080p"));
+        assert_eq!(lax.parse(" "), Err(ErrorMatch::EmptyInput));
+        let (_, warnings) = lax.parse_lenient(name);
+        assert!(matches!(warnings[0], Warning::NoTitle { .. }));
+    }
+
+    #[test]
+    fn paths() {
+        let parser = Parser::builder().replace_separators(false).build();
+        let m = parser
+            .parse_path("The.Wire.2002/Season 1/The.Wire.S01E05.720p.mkv")
+            .unwrap();
+        assert_eq!(m.title(), "The.Wire");
+        assert_eq!(m.episode(), Some(5));
+        assert_eq!(
+            Parser::new().parse_path("Show.S01/Show.S01E02.mkv"),
+            Metadata::from_path("Show.S01/Show.S01E02.mkv")
+        );
+
+        let lax = Parser::builder().strict(false).build();
+        let m = lax.parse_path("Season 1/S01E02.720p.mkv").unwrap();
+        assert_eq!(m.maybe_title(), None);
+        assert_eq!(m.episode(), Some(2));
+    }
+
+    #[test]
+    fn magnets() {
+        let uri = "magnet:?xt=urn:btih:c12fe1c06bba254a9dc9f519b335aa7c1367a88a&dn=S01E02.720p";
+        let magnet = Parser::builder()
+            .strict(false)
+            .build()
+            .parse_magnet(uri)
+            .unwrap();
+        let m = magnet.metadata().unwrap().unwrap();
+        assert_eq!(m.maybe_title(), None);
+        assert_eq!(m.episode(), Some(2));
+        assert_eq!(Parser::new().parse_magnet(uri), Metadata::from_magnet(uri));
+    }
+}
+
+#[cfg(test)]
+mod extra_patterns {
+    use crate::{Field, Parser, Pattern};
+    use regex::Regex;
+
+    fn pattern(regex: &str, before_title: bool) -> Pattern {
+        Pattern::new(Regex::new(regex).unwrap(), before_title, false, false)
+    }
+
+    #[test]
+    fn value_group_trims_title() {
+        let parser = Parser::builder()
+            .pattern("team", pattern(r"\[(?P<value>INTERNAL-[A-Z]+)\]", false))
+            .build();
+        let name = "Movie.Name.2019.1080p.WEB.x264 [INTERNAL-TEAM]";
+        let m = parser.parse(name).unwrap();
+        assert_eq!(m.extra()["team"], "INTERNAL-TEAM");
+        assert_eq!(m.title(), "Movie Name");
+        let span = m.span(Field::Extra("team".to_string())).unwrap();
+        assert_eq!(span.as_str(name), "INTERNAL-TEAM");
+    }
+
+    #[test]
+    fn whole_match_and_before_title() {
+        let parser = Parser::builder()
+            .pattern("tracker", pattern(r"^\{[a-z]+\}", true))
+            .pattern("cut", pattern(r"(?i)fan ?cut", false))
+            .build();
+        let m = parser
+            .parse("{mytracker} The Matrix Fan Cut 1999 720p BluRay")
+            .unwrap();
+        assert_eq!(m.extra()["tracker"], "{mytracker}");
+        assert_eq!(m.extra()["cut"], "Fan Cut");
+        assert_eq!(m.title(), "The Matrix");
+        assert_eq!(m.year(), Some(1999));
+        let extras: Vec<_> = m
+            .spans()
+            .iter()
+            .filter(|s| matches!(s.field(), Field::Extra(_)))
+            .map(|s| s.field())
+            .collect();
+        assert_eq!(
+            extras,
+            vec![
+                Field::Extra("tracker".to_string()),
+                Field::Extra("cut".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn replaced_and_unmatched() {
+        let parser = Parser::builder()
+            .pattern("label", pattern(r"NOPE", false))
+            .pattern("label", pattern(r"(?P<value>DUB)BED", false))
+            .pattern("missing", pattern(r"XYZZY", false))
+            .build();
+        let m = parser.parse("Some Show S01E01 DUBBED 720p").unwrap();
+        assert_eq!(m.extra().len(), 1);
+        assert_eq!(m.extra()["label"], "DUB");
+        assert!(Parser::new()
+            .parse("Some Show S01E01 DUBBED 720p")
+            .unwrap()
+            .extra()
+            .is_empty());
+    }
+}
@@ -0,0 +1,165 @@
+use crate::bencode::{self, Value};
+use crate::error::{ErrorMatch, TorrentError};
+use crate::metadata::{self, Metadata};
+use crate::parser::Parser;
+use std::convert::TryFrom;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+/// Extensions of the files in a torrent that are parsed as videos.
+const VIDEO_EXTENSIONS: &[&str] = &[
+    "3gp", "avi", "divx", "flv", "m2ts", "m4v", "mkv", "mov", "mp4", "mpeg", "mpg", "ogm", "ts",
+    "vob", "webm", "wmv",
+];
+
+/// What a `.torrent` file says about its name and the videos in it.
+///
+///```
+/// use torrent_name_parser::TorrentMetadata;
+///
+/// let torrent = b"d4:infod5:filesld6:lengthi1024e4:pathl15:Show.S01E01.mkveed6:lengthi10e4:pathl8:info.nfoeee4:name21:Show.S01.1080p.WEB-DLee";
+/// let t = TorrentMetadata::from_bytes(torrent).unwrap();
+/// assert_eq!(t.metadata().unwrap().title(), "Show");
+/// assert_eq!(t.files().len(), 1);
+/// assert_eq!(t.files()[0].metadata().episode(), Some(1));
+/// assert_eq!(t.files()[0].metadata().resolution(), Some("1080p"));
+///```
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TorrentMetadata {
+    name: String,
+    metadata: Result<Metadata, ErrorMatch>,
+    files: Vec<TorrentFile>,
+}
+
+/// A video in a torrent, parsed with the torrent name as its folder.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TorrentFile {
+    path: PathBuf,
+    length: u64,
+    metadata: Metadata,
+}
+
+impl TorrentMetadata {
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, TorrentError> {
+        Parser::default().parse_torrent_file(path)
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, TorrentError> {
+        Parser::default().parse_torrent(bytes)
+    }
+
+    /// `info.name`, the name of the single file or the folder of a multi-file torrent.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    /// `info.name` parsed, which fails when it has no title even though its files may
+    /// have one.
+    pub fn metadata(&self) -> Result<&Metadata, &ErrorMatch> {
+        self.metadata.as_ref()
+    }
+    /// The videos in the torrent, in the order it lists them.
+    pub fn files(&self) -> &[TorrentFile] {
+        &self.files
+    }
+}
+
+impl Parser {
+    /// Reads a `.torrent` file like `TorrentMetadata::from_file`, parsing its names with
+    /// this parser.
+    pub fn parse_torrent_file(
+        &self,
+        path: impl AsRef<Path>,
+    ) -> Result<TorrentMetadata, TorrentError> {
+        self.parse_torrent(&fs::read(path)?)
+    }
+
+    /// Reads a `.torrent` file's contents like `TorrentMetadata::from_bytes`, parsing its
+    /// names with this parser.
+    pub fn parse_torrent(&self, bytes: &[u8]) -> Result<TorrentMetadata, TorrentError> {
+        let root = bencode::decode(bytes).map_err(TorrentError::Bencode)?;
+        let info = root.get("info").ok_or(TorrentError::Missing("info"))?;
+        let name = utf8_or_raw(info, "name").ok_or(TorrentError::Missing("info.name"))?;
+        // The files can still be parsed when the name has no title, eg: `Season 1`.
+        let metadata = self.parse(name);
+
+        let files = match info.get("files").and_then(Value::as_list) {
+            Some(files) => files
+                .iter()
+                .filter_map(|file| {
+                    let parts = file
+                        .get("path.utf-8")
+                        .or_else(|| file.get("path"))?
+                        .as_list()?;
+                    let mut path = PathBuf::new();
+                    for part in parts {
+                        path.push(part.as_str().filter(|part| !part.is_empty())?);
+                    }
+                    Some((path, length(file)))
+                })
+                .collect(),
+            None => vec![(PathBuf::from(name), length(info))],
+        };
+        let files = files
+            .into_iter()
+            .filter(|(path, _)| is_relative(path) && is_video(path))
+            .map(|(path, length)| {
+                let (_, metadata, _) = metadata::parse_path(&Path::new(name).join(&path), self);
+                TorrentFile {
+                    path,
+                    length,
+                    metadata,
+                }
+            })
+            .collect();
+
+        Ok(TorrentMetadata {
+            name: name.to_string(),
+            metadata,
+            files,
+        })
+    }
+}
+
+impl TorrentFile {
+    /// The path inside the torrent, eg: `Season 1/Show.S01E01.mkv`.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+    /// Size in bytes.
+    pub fn length(&self) -> u64 {
+        self.length
+    }
+    pub fn metadata(&self) -> &Metadata {
+        &self.metadata
+    }
+}
+
+/// Prefers the `.utf-8` copy of a key some clients write next to the original.
+fn utf8_or_raw<'a>(dict: &Value<'a>, key: &str) -> Option<&'a str> {
+    dict.get(&format!("{}.utf-8", key))
+        .or_else(|| dict.get(key))?
+        .as_str()
+}
+
+fn length(dict: &Value) -> u64 {
+    dict.get("length")
+        .and_then(Value::as_int)
+        .and_then(|n| u64::try_from(n).ok())
+        .unwrap_or_default()
+}
+
+/// Whether every part of a path is a plain name, so it can't point outside the torrent's
+/// folder with `..` or an absolute path.
+fn is_relative(path: &Path) -> bool {
+    path.components().next().is_some()
+        && path.components().all(|c| matches!(c, Component::Normal(_)))
+}
+
+fn is_video(path: &Path) -> bool {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .map(|e| VIDEO_EXTENSIONS.contains(&e.to_lowercase().as_str()))
+        .unwrap_or(false)
+}
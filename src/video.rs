@@ -0,0 +1,203 @@
+use std::fmt;
+
+/// Vertical resolution of the video, ordered from lowest to highest.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Resolution {
+    P360,
+    P480,
+    P540,
+    P576,
+    P720,
+    P1080,
+    P1440,
+    P2160,
+    P4320,
+}
+
+impl Resolution {
+    pub(crate) fn from_tag(tag: &str) -> Option<Self> {
+        let height: u32 = tag.trim_end_matches(['p', 'P']).parse().ok()?;
+        match height {
+            360 => Some(Resolution::P360),
+            480 => Some(Resolution::P480),
+            540 => Some(Resolution::P540),
+            576 => Some(Resolution::P576),
+            720 => Some(Resolution::P720),
+            1080 => Some(Resolution::P1080),
+            1440 => Some(Resolution::P1440),
+            2160 => Some(Resolution::P2160),
+            4320 => Some(Resolution::P4320),
+            _ => None,
+        }
+    }
+
+    /// Height of the video in pixels.
+    pub fn height(&self) -> u32 {
+        match self {
+            Resolution::P360 => 360,
+            Resolution::P480 => 480,
+            Resolution::P540 => 540,
+            Resolution::P576 => 576,
+            Resolution::P720 => 720,
+            Resolution::P1080 => 1080,
+            Resolution::P1440 => 1440,
+            Resolution::P2160 => 2160,
+            Resolution::P4320 => 4320,
+        }
+    }
+}
+
+impl fmt::Display for Resolution {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}p", self.height())
+    }
+}
+
+/// Where the release was ripped from, ordered roughly from worst to best.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Source {
+    Cam,
+    Telesync,
+    Screener,
+    Dvd,
+    Tv,
+    HdRip,
+    WebRip,
+    WebDl,
+    BluRay,
+}
+
+impl Source {
+    pub(crate) fn from_tag(tag: &str) -> Option<Self> {
+        let tag: String = tag
+            .chars()
+            .filter(|c| c.is_ascii_alphanumeric())
+            .collect::<String>()
+            .to_lowercase();
+        let tag = tag.trim_start_matches("ppv");
+        if tag.contains("cam") {
+            Some(Source::Cam)
+        } else if tag == "ts" {
+            Some(Source::Telesync)
+        } else if tag == "dvdscr" {
+            Some(Source::Screener)
+        } else if tag == "dvdrip" {
+            Some(Source::Dvd)
+        } else if tag == "hdtv" || tag == "pdtv" {
+            Some(Source::Tv)
+        } else if tag == "hdrip" {
+            Some(Source::HdRip)
+        } else if tag == "webrip" || tag == "wbbrip" {
+            Some(Source::WebRip)
+        } else if tag.starts_with("web") {
+            Some(Source::WebDl)
+        } else if tag == "brrip" || tag == "bluray" {
+            Some(Source::BluRay)
+        } else {
+            None
+        }
+    }
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Source::Cam => "CAM",
+            Source::Telesync => "TS",
+            Source::Screener => "SCR",
+            Source::Dvd => "DVDRip",
+            Source::Tv => "HDTV",
+            Source::HdRip => "HDRip",
+            Source::WebRip => "WEBRip",
+            Source::WebDl => "WEB-DL",
+            Source::BluRay => "BluRay",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Video codec, ordered from oldest to newest.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum VideoCodec {
+    Xvid,
+    H264,
+    H265,
+}
+
+impl VideoCodec {
+    pub(crate) fn from_tag(tag: &str) -> Option<Self> {
+        let tag = tag.to_lowercase().replace(['.', '/'], "");
+        match tag.as_str() {
+            "xvid" => Some(VideoCodec::Xvid),
+            "x264" | "h264" => Some(VideoCodec::H264),
+            "x265" | "h265" | "hevc" | "hev" => Some(VideoCodec::H265),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for VideoCodec {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            VideoCodec::Xvid => "XviD",
+            VideoCodec::H264 => "H.264",
+            VideoCodec::H265 => "H.265",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Dynamic range format of the video.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum HdrFormat {
+    Sdr,
+    Hdr,
+    Hdr10,
+    Hdr10Plus,
+    DolbyVision,
+    Hlg,
+}
+
+impl HdrFormat {
+    /// Every format in a tag such as `DV.HDR10`, in the order they appear.
+    pub(crate) fn from_tag(tag: &str) -> Vec<Self> {
+        let tag = tag
+            .to_lowercase()
+            .replace("dolby vision", "dv")
+            .replace("dolby.vision", "dv");
+        let mut formats = Vec::new();
+        for part in tag.split([' ', '.', '-']) {
+            let format = match part {
+                "dv" | "dovi" | "dolbyvision" => HdrFormat::DolbyVision,
+                "hdr10+" | "hdr10plus" => HdrFormat::Hdr10Plus,
+                "hdr10" => HdrFormat::Hdr10,
+                "hdr" => HdrFormat::Hdr,
+                "hlg" => HdrFormat::Hlg,
+                "sdr" => HdrFormat::Sdr,
+                _ => continue,
+            };
+            if !formats.contains(&format) {
+                formats.push(format);
+            }
+        }
+        formats
+    }
+}
+
+impl fmt::Display for HdrFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            HdrFormat::Sdr => "SDR",
+            HdrFormat::Hdr => "HDR",
+            HdrFormat::Hdr10 => "HDR10",
+            HdrFormat::Hdr10Plus => "HDR10+",
+            HdrFormat::DolbyVision => "DV",
+            HdrFormat::Hlg => "HLG",
+        };
+        f.write_str(name)
+    }
+}
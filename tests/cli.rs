@@ -0,0 +1,119 @@
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+const MOVIE: &str = "Hercules (2014) 1080p BrRip H264 - YIFY";
+const NO_TITLE: &str = "S01E01.1080p";
+
+fn tnp(args: &[&str], stdin: Option<&str>) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_tnp"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    let mut input = child.stdin.take().unwrap();
+    input
+        .write_all(stdin.unwrap_or_default().as_bytes())
+        .unwrap();
+    drop(input);
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(output: &Output) -> Vec<String> {
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(str::to_string)
+        .collect()
+}
+
+#[test]
+fn table() {
+    let output = tnp(&[MOVIE], None);
+    assert_eq!(output.status.code(), Some(0));
+    let lines = stdout(&output);
+    assert_eq!(lines.len(), 2);
+    assert!(lines[0].starts_with("NAME"));
+    assert!(lines[0].ends_with("ERROR"));
+    let row = lines[1].strip_prefix(MOVIE).unwrap();
+    let cells: Vec<&str> = row.split_whitespace().collect();
+    assert_eq!(
+        cells,
+        ["Hercules", "2014", "1080p", "BrRip", "H264", "YIFY"]
+    );
+}
+
+#[test]
+fn table_failure() {
+    let output = tnp(&[MOVIE, NO_TITLE], None);
+    assert_eq!(output.status.code(), Some(1));
+    let lines = stdout(&output);
+    assert_eq!(lines.len(), 3);
+    let row = lines[2].strip_prefix(NO_TITLE).unwrap();
+    assert!(row.trim_start().starts_with("couldn't find a title"));
+    assert!(String::from_utf8_lossy(&output.stderr).contains(NO_TITLE));
+}
+
+#[test]
+fn tsv() {
+    let output = tnp(&["-f", "tsv", MOVIE, NO_TITLE], None);
+    assert_eq!(output.status.code(), Some(1));
+    let lines = stdout(&output);
+    assert_eq!(lines.len(), 3);
+    assert_eq!(
+        lines[0],
+        "name\ttitle\tyear\tseason\tepisode\tresolution\tquality\tcodec\taudio\tgroup\textension\terror"
+    );
+    let row: Vec<&str> = lines[1].split('\t').collect();
+    assert_eq!(row.len(), 12);
+    assert_eq!(row[..3], [MOVIE, "Hercules", "2014"]);
+    assert_eq!(row[11], "");
+    let row: Vec<&str> = lines[2].split('\t').collect();
+    assert_eq!(row.len(), 12);
+    assert_eq!(row[0], NO_TITLE);
+    assert_eq!(row[1], "");
+    assert!(row[11].starts_with("couldn't find a title"));
+}
+
+#[test]
+fn json() {
+    let output = tnp(&["--format", "json", MOVIE, NO_TITLE], None);
+    assert_eq!(output.status.code(), Some(1));
+    let lines = stdout(&output);
+    assert_eq!(lines.len(), 2);
+    let ok: serde_json::Value = serde_json::from_str(&lines[0]).unwrap();
+    assert_eq!(ok["name"], MOVIE);
+    assert_eq!(ok["metadata"]["title"], "Hercules");
+    assert_eq!(ok["metadata"]["year"], 2014);
+    let err: serde_json::Value = serde_json::from_str(&lines[1]).unwrap();
+    assert_eq!(err["name"], NO_TITLE);
+    assert!(err["error"].as_str().unwrap().contains(NO_TITLE));
+    assert!(output.stderr.is_empty());
+}
+
+#[test]
+fn stdin() {
+    let input = format!("{}\n\n  \nNarcos.S01E10.720p.WEB\n", MOVIE);
+    let output = tnp(&["-f", "tsv"], Some(&input));
+    assert_eq!(output.status.code(), Some(0));
+    let names: Vec<String> = stdout(&output)
+        .iter()
+        .skip(1)
+        .map(|line| line.split('\t').next().unwrap().to_string())
+        .collect();
+    assert_eq!(names, [MOVIE, "Narcos.S01E10.720p.WEB"]);
+}
+
+#[test]
+fn usage() {
+    let output = tnp(&["--help"], None);
+    assert_eq!(output.status.code(), Some(0));
+    assert!(String::from_utf8_lossy(&output.stdout).starts_with("Usage: tnp"));
+
+    for args in [&["-f", "xml"][..], &["-f"], &["--verbose"]] {
+        let output = tnp(args, None);
+        assert_eq!(output.status.code(), Some(2), "{:?}", args);
+        assert!(output.stdout.is_empty());
+        assert!(String::from_utf8_lossy(&output.stderr).contains("Usage: tnp"));
+    }
+}